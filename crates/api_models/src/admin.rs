@@ -1462,6 +1462,41 @@ impl MerchantConnectorListResponse {
     }
 }
 
+/// Details of a Merchant Connector, with the values of the connector account details masked
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MerchantConnectorMaskedResponse {
+    /// Type of the Connector for the financial use case. Could range from Payments to Accounting to Banking.
+    #[schema(value_type = ConnectorType, example = "payment_processor")]
+    pub connector_type: api_enums::ConnectorType,
+
+    /// Name of the Connector
+    #[schema(value_type = Connector, example = "stripe")]
+    pub connector_name: String,
+
+    /// A unique label to identify the connector account created under a profile
+    #[schema(example = "stripe_US_travel")]
+    pub connector_label: Option<String>,
+
+    /// Unique ID of the merchant connector account
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR", value_type = String)]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+
+    /// Identifier for the profile, if not provided default will be chosen from merchant account
+    #[schema(max_length = 64, value_type = String)]
+    pub profile_id: id_type::ProfileId,
+
+    /// The connector account details, with only the keys retained and every value masked
+    #[schema(value_type = Object)]
+    pub connector_account_details: serde_json::Value,
+
+    /// A boolean value to indicate if the connector is disabled. By default, its value is false.
+    #[schema(default = false, example = false)]
+    pub disabled: Option<bool>,
+
+    #[schema(value_type = ConnectorStatus, example = "inactive")]
+    pub status: api_enums::ConnectorStatus,
+}
+
 /// Create a new Merchant Connector for the merchant account. The connector could be a payment processor / facilitator / acquirer or specialized services like Fraud / Accounting etc."
 #[cfg(feature = "v1")]
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -1720,6 +1755,15 @@ pub struct FrmPaymentMethodType {
     #[schema(value_type = FrmAction)]
     pub action: api_enums::FrmAction,
 }
+/// The merchant connector accounts which have enabled each payment method
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PaymentMethodCapabilityMatrix {
+    pub payment_methods: HashMap<
+        common_enums::PaymentMethod,
+        Vec<common_utils::id_type::MerchantConnectorAccountId>,
+    >,
+}
+
 /// Details of all the payment methods enabled for the connector for the given merchant account
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(deny_unknown_fields)]
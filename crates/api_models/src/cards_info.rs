@@ -28,4 +28,6 @@ pub struct CardInfoResponse {
     pub card_sub_type: Option<String>,
     #[schema(example = "INDIA")]
     pub card_issuing_country: Option<String>,
+    #[schema(example = "premium")]
+    pub card_category: Option<String>,
 }
@@ -253,6 +253,59 @@ impl CustomerResponse {
     }
 }
 
+/// Personal data held for a customer, used to serve data subject export requests.
+///
+/// The details contain decrypted PII and are wrapped so that they have to be explicitly exposed.
+/// This must only be used in authenticated export flows.
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone)]
+pub struct CustomerDataExport(Secret<CustomerExportDetails>);
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+impl CustomerDataExport {
+    pub fn new(details: CustomerExportDetails) -> Self {
+        Self(Secret::new(details))
+    }
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+impl ExposeInterface<CustomerExportDetails> for CustomerDataExport {
+    fn expose(self) -> CustomerExportDetails {
+        self.0.expose()
+    }
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CustomerExportDetails {
+    /// The identifier for the customer object
+    pub customer_id: id_type::CustomerId,
+    /// The customer's name
+    pub name: Option<String>,
+    /// The customer's email address
+    pub email: Option<String>,
+    /// The customer's phone number
+    pub phone: Option<String>,
+    /// The country code for the customer phone number
+    pub phone_country_code: Option<String>,
+    /// A timestamp (ISO 8601 code) that determines when the customer was created
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    /// The metadata stored for the customer
+    pub metadata: Option<serde_json::Value>,
+}
+
+/// The contact details available for a customer, checked before a mandate is created for them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+pub struct MandateEligibility {
+    /// Whether the customer has an email address
+    pub has_email: bool,
+    /// Whether the customer has a phone number
+    pub has_phone: bool,
+    /// Whether the customer has a name
+    pub has_name: bool,
+}
+
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
 #[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct CustomerResponse {
@@ -1,12 +1,12 @@
 use std::collections::HashMap;
 
-use common_utils::types::TimeRange;
+use common_utils::types::{MinorUnit, TimeRange};
 use masking::{Deserialize, Serialize};
 use serde::de::Error;
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
-use super::enums::{DisputeStage, DisputeStatus};
+use super::enums::{DisputeCategory, DisputeStage, DisputeStatus};
 use crate::{admin::MerchantConnectorInfo, enums, files};
 
 #[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
@@ -18,8 +18,9 @@ pub struct DisputeResponse {
     pub payment_id: common_utils::id_type::PaymentId,
     /// The identifier for payment_attempt
     pub attempt_id: String,
-    /// The dispute amount
-    pub amount: String,
+    /// The dispute amount in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
     /// The three-letter ISO currency code
     pub currency: String,
     /// Stage of the dispute
@@ -32,6 +33,8 @@ pub struct DisputeResponse {
     pub connector_status: String,
     /// Dispute id sent by connector
     pub connector_dispute_id: String,
+    /// Transaction id of the disputed payment attempt sent by connector
+    pub connector_transaction_id: Option<String>,
     /// Reason of dispute sent by connector
     pub connector_reason: Option<String>,
     /// Reason code of dispute sent by connector
@@ -54,12 +57,38 @@ pub struct DisputeResponse {
     /// The `merchant_connector_id` of the connector / processor through which the dispute was processed
     #[schema(value_type = Option<String>)]
     pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    /// The evidence files submitted for the dispute, keyed by the evidence type
+    #[schema(value_type = Option<Object>)]
+    pub evidence: Option<serde_json::Value>,
+    /// Indicates how soon the evidence for the dispute has to be submitted
+    pub urgency: DisputeUrgency,
+    /// Category of the reason for the dispute, derived from the reason code sent by the connector
+    pub dispute_category: Option<DisputeCategory>,
+}
+
+/// Indicates how soon the evidence for a dispute has to be submitted
+#[derive(Clone, Copy, Debug, Serialize, ToSchema, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DisputeUrgency {
+    /// The deadline to submit evidence has passed
+    Overdue,
+    /// The deadline to submit evidence is within the next 48 hours
+    DueSoon,
+    /// The deadline to submit evidence is more than 48 hours away
+    Ample,
+    /// The connector did not provide a deadline to submit evidence
+    NoDeadline,
 }
 
 #[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
 pub struct DisputeResponsePaymentsRetrieve {
     /// The identifier for dispute
     pub dispute_id: String,
+    /// The dispute amount in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+    /// The three-letter ISO currency code
+    pub currency: String,
     /// Stage of the dispute
     pub dispute_stage: DisputeStage,
     /// Status of the dispute
@@ -19,3 +19,18 @@ pub struct FileMetadataResponse {
     /// File availability
     pub available: bool,
 }
+
+#[derive(Debug, serde::Serialize, ToSchema, Clone, PartialEq, Eq)]
+pub struct FileDownloadDescriptor {
+    /// ID of the file created
+    pub file_id: String,
+    /// Reference of the file at the file upload provider
+    pub provider_file_id: Option<String>,
+    /// The provider with which the file was uploaded
+    #[schema(value_type = Option<FileUploadProvider>)]
+    pub file_upload_provider: Option<common_enums::FileUploadProvider>,
+    /// Type of the file
+    pub file_type: String,
+    /// Reason for the file not being available for download, present only when the file is unavailable
+    pub not_available_reason: Option<String>,
+}
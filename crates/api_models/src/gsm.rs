@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use utoipa::ToSchema;
 
 use crate::enums::Connector;
@@ -134,7 +136,7 @@ pub struct GsmResponse {
     /// optional error provided by the router
     pub router_error: Option<String>,
     /// decision to be taken for auto retries flow
-    pub decision: String,
+    pub decision: GsmDecision,
     /// indicates if step_up retry is possible
     pub step_up_possible: bool,
     /// error code unified across the connectors
@@ -142,3 +144,9 @@ pub struct GsmResponse {
     /// error message unified across the connectors
     pub unified_message: Option<String>,
 }
+
+#[derive(serde::Serialize, Debug, ToSchema)]
+pub struct GsmGroupedResponse {
+    /// GSM rules grouped by the name of the connector they belong to
+    pub connectors: HashMap<String, Vec<GsmResponse>>,
+}
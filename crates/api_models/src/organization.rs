@@ -3,6 +3,7 @@ use utoipa::ToSchema;
 pub struct OrganizationNew {
     pub org_id: id_type::OrganizationId,
     pub org_name: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
 }
 
 impl OrganizationNew {
@@ -10,6 +11,7 @@ impl OrganizationNew {
         Self {
             org_id: id_type::OrganizationId::default(),
             org_name,
+            metadata: None,
         }
     }
 }
@@ -1136,6 +1136,12 @@ pub struct PaymentAttemptResponse {
     /// The payment attempt amount. Amount for the payment in lowest denomination of the currency. (i.e) in cents for USD denomination, in paisa for INR denomination etc.,
     #[schema(value_type = i64, example = 6540)]
     pub amount: MinorUnit,
+    /// The surcharge amount applied to the payment attempt
+    #[schema(value_type = Option<i64>, example = 100)]
+    pub surcharge_amount: Option<MinorUnit>,
+    /// The tax applied on the surcharge amount of the payment attempt
+    #[schema(value_type = Option<i64>, example = 18)]
+    pub tax_on_surcharge_amount: Option<MinorUnit>,
     /// The currency of the amount of the payment attempt
     #[schema(value_type = Option<Currency>, example = "USD")]
     pub currency: Option<enums::Currency>,
@@ -1172,12 +1178,17 @@ pub struct PaymentAttemptResponse {
     pub payment_token: Option<String>,
     /// Additional data related to some connectors
     pub connector_metadata: Option<serde_json::Value>,
+    /// The 3DS and redirection details from `connector_metadata`, if it has a recognized shape
+    pub parsed_connector_metadata: Option<ConnectorMetadataSummary>,
     /// Payment Experience for the current payment
     #[schema(value_type = Option<PaymentExperience>, example = "redirect_to_url")]
     pub payment_experience: Option<enums::PaymentExperience>,
     /// Payment Method Type
     #[schema(value_type = Option<PaymentMethodType>, example = "google_pay")]
     pub payment_method_type: Option<enums::PaymentMethodType>,
+    /// The name of the bank that issued the card, for card payment attempts
+    #[schema(example = "JP Morgan Chase")]
+    pub card_issuer: Option<String>,
     /// Reference to the payment at connector side
     #[schema(value_type = Option<String>, example = "993672945374576J")]
     pub reference_id: Option<String>,
@@ -1189,11 +1200,86 @@ pub struct PaymentAttemptResponse {
     pub client_source: Option<String>,
     /// Value passed in X-CLIENT-VERSION header during payments confirm request by the client
     pub client_version: Option<String>,
+    /// The error details of the attempt, grouped together, if there was an error while calling the connector
+    pub error_details: Option<AttemptErrorDetails>,
 }
 
-#[derive(
-    Default, Debug, serde::Serialize, Clone, PartialEq, ToSchema, router_derive::PolymorphicSchema,
-)]
+#[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, PartialEq, Eq, ToSchema)]
+pub struct ConnectorMetadataSummary {
+    /// The url to which the customer is redirected to complete the payment
+    pub redirect_url: Option<String>,
+    /// The version of the 3DS protocol used to authenticate the payment
+    #[schema(example = "2.2.0")]
+    pub three_ds_version: Option<String>,
+    /// The Electronic Commerce Indicator received after 3DS authentication
+    #[schema(example = "05")]
+    pub eci: Option<String>,
+}
+
+impl ConnectorMetadataSummary {
+    pub fn is_empty(&self) -> bool {
+        self.redirect_url.is_none() && self.three_ds_version.is_none() && self.eci.is_none()
+    }
+}
+
+/// Transaction details of an initial payment, required to make subsequent merchant initiated payments
+#[derive(Debug, serde::Serialize, Clone, PartialEq, Eq, ToSchema)]
+pub struct NetworkTransactionContext {
+    /// The transaction id of the initial payment at the connector
+    pub connector_transaction_id: String,
+    /// The transaction id assigned by the card network to the initial payment
+    pub network_transaction_id: Option<String>,
+    /// The connector used for the initial payment
+    pub connector: Option<String>,
+}
+
+/// Latency of the connector call made for a payment attempt, exposed when latency tracking is
+/// requested through the `x-hs-latency` header
+#[derive(Debug, serde::Serialize, Clone, PartialEq, Eq, ToSchema)]
+pub struct AttemptLatencyMetrics {
+    /// Unique identifier for the attempt
+    pub attempt_id: String,
+    /// The connector to which the call was made
+    pub connector: String,
+    /// Time taken for the connector call to complete, in milliseconds
+    #[schema(example = 450)]
+    pub connector_call_duration_ms: u64,
+}
+
+#[derive(Default, Debug, serde::Serialize, Clone, PartialEq, ToSchema)]
+pub struct AttemptErrorDetails {
+    /// The error code received from the connector
+    pub code: Option<String>,
+    /// The error message received from the connector
+    pub message: Option<String>,
+    /// Error code unified across the connectors
+    pub unified_code: Option<String>,
+    /// Error message unified across the connectors
+    pub unified_message: Option<String>,
+}
+
+#[derive(Default, Debug, serde::Serialize, Clone, PartialEq, ToSchema)]
+pub struct PaymentAttemptListSummary {
+    /// The number of attempts made for the payment
+    pub attempts_count: usize,
+    /// The distinct connectors with which the payment was attempted, in the order they were first tried
+    pub connectors: Vec<String>,
+    /// The status of the most recent attempt
+    #[schema(value_type = Option<AttemptStatus>, example = "charged")]
+    pub terminal_status: Option<enums::AttemptStatus>,
+}
+
+#[derive(Debug, serde::Serialize, Clone, PartialEq, Eq, ToSchema)]
+pub struct AttemptStatusDisplay {
+    /// The status of the attempt
+    #[schema(value_type = AttemptStatus, example = "partial_charged_and_chargeable")]
+    pub status: enums::AttemptStatus,
+    /// A merchant friendly description of the attempt status
+    #[schema(example = "Partially captured, more capturable")]
+    pub label: String,
+}
+
+#[derive(Debug, serde::Serialize, Clone, PartialEq, ToSchema, router_derive::PolymorphicSchema)]
 pub struct CaptureResponse {
     /// Unique identifier for the capture
     pub capture_id: String,
@@ -1222,6 +1308,35 @@ pub struct CaptureResponse {
     pub error_reason: Option<String>,
     /// Reference to the capture at connector side
     pub reference_id: Option<String>,
+    /// The identifier for the profile to which the capture belongs
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+    /// The identifier for the merchant connector account through which the capture was made
+    #[schema(value_type = Option<String>)]
+    pub merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+    /// Time at which the capture was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Default, Debug, serde::Serialize, Clone, PartialEq, ToSchema)]
+pub struct CaptureSummaryResponse {
+    /// The total amount captured across all the successful captures, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 6540)]
+    pub total_captured_amount: MinorUnit,
+    /// The currency of the captures
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<enums::Currency>,
+    /// The number of captures that were charged successfully
+    pub successful_captures_count: usize,
+    /// The number of captures that failed
+    pub failed_captures_count: usize,
+    /// The status of the capture with the highest capture sequence
+    #[schema(value_type = Option<CaptureStatus>, example = "charged")]
+    pub latest_capture_status: Option<enums::CaptureStatus>,
+    /// The list of captures made for the payment
+    pub captures: Vec<CaptureResponse>,
 }
 
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq, Eq)]
@@ -1428,6 +1543,17 @@ pub struct MandateAmountData {
     pub metadata: Option<pii::SecretSerdeValue>,
 }
 
+/// The amount of a multi use mandate which is yet to be consumed
+#[derive(Clone, Copy, Eq, PartialEq, Debug, ToSchema, serde::Serialize)]
+pub struct MandateAmountRemaining {
+    /// The amount remaining to be debited using the mandate
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+    /// The currency of the mandate
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+}
+
 #[derive(Eq, PartialEq, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum MandateType {
@@ -4637,12 +4763,72 @@ pub struct ExternalAuthenticationDetailsResponse {
     pub ds_transaction_id: Option<String>,
     /// Message Version
     pub version: Option<String>,
+    /// Whether the message version supports 3DS 2.x
+    pub supports_3ds2: bool,
+    /// Whether the issuer mandated a challenge for the authentication
+    pub challenge_mandated: bool,
     /// Error Code
     pub error_code: Option<String>,
     /// Error Message
     pub error_message: Option<String>,
 }
 
+/// Complete details of an external authentication
+#[derive(Clone, Debug, PartialEq, serde::Serialize, ToSchema)]
+pub struct AuthenticationRetrieveResponse {
+    /// The identifier for the authentication
+    pub authentication_id: String,
+    /// The identifier for the merchant
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The identifier for the payment associated with the authentication
+    #[schema(value_type = Option<String>)]
+    pub payment_id: Option<id_type::PaymentId>,
+    /// The identifier for the business profile
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+    /// The connector used for the authentication
+    pub authentication_connector: String,
+    /// Authentication Type - Challenge / Frictionless
+    #[schema(value_type = Option<DecoupledAuthenticationType>)]
+    pub authentication_flow: Option<enums::DecoupledAuthenticationType>,
+    /// Authentication Status
+    #[schema(value_type = AuthenticationStatus)]
+    pub status: enums::AuthenticationStatus,
+    /// Transaction status sent by the access control server
+    #[schema(value_type = Option<TransactionStatus>)]
+    pub trans_status: Option<common_enums::TransactionStatus>,
+    /// Electronic Commerce Indicator (eci)
+    pub electronic_commerce_indicator: Option<String>,
+    /// Cardholder Authentication Verification Value
+    #[schema(value_type = Option<String>)]
+    pub cavv: Option<Secret<String>>,
+    /// DS Transaction ID
+    pub ds_transaction_id: Option<String>,
+    /// Message Version
+    pub message_version: Option<String>,
+    /// URL of the access control server to which the challenge request is to be posted
+    pub acs_url: Option<String>,
+    /// Challenge request to be sent to the access control server
+    pub challenge_request: Option<String>,
+    /// Reference number of the access control server
+    pub acs_reference_number: Option<String>,
+    /// Transaction ID of the access control server
+    pub acs_trans_id: Option<String>,
+    /// Error Code
+    pub error_code: Option<String>,
+    /// Error Message
+    pub error_message: Option<String>,
+    /// Time at which the authentication was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// Time at which the authentication was last modified
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
 #[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct PaymentListConstraints {
@@ -4733,6 +4919,26 @@ pub struct IncrementalAuthorizationResponse {
     pub previously_authorized_amount: MinorUnit,
 }
 
+/// Error details of an authorization that was not successful at the connector
+#[derive(Clone, Default, Debug, PartialEq, serde::Serialize, ToSchema)]
+pub struct AuthorizationError {
+    /// Error code sent by the connector for authorization
+    pub code: Option<String>,
+    /// Error message sent by the connector for authorization
+    pub message: Option<String>,
+}
+
+#[derive(Clone, Default, Debug, PartialEq, serde::Serialize, ToSchema)]
+pub struct IncrementalAuthorizationHistory {
+    /// List of incremental authorizations made for the payment, in the order they were created
+    pub authorizations: Vec<IncrementalAuthorizationResponse>,
+    /// Amount authorized for the payment as of the latest successful incremental authorization
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub total_authorized_amount: Option<MinorUnit>,
+    /// Number of successful incremental authorizations
+    pub successful_authorizations_count: usize,
+}
+
 #[derive(Clone, Debug, serde::Serialize)]
 pub struct PaymentListResponseV2 {
     /// The number of payments included in the list for given constraints
@@ -6388,6 +6594,9 @@ pub struct RetrievePaymentLinkResponse {
     /// Date and time of Expiration for Payment Link
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub expiry: Option<PrimitiveDateTime>,
+    /// Number of seconds remaining until the Payment Link expires, 0 if it has already expired
+    #[schema(example = 900)]
+    pub seconds_until_expiry: Option<i64>,
     /// Description for Payment Link
     pub description: Option<String>,
     /// Status Of the Payment Link
@@ -6398,6 +6607,20 @@ pub struct RetrievePaymentLinkResponse {
     pub secure_link: Option<String>,
 }
 
+/// Minimal payment link details required to render the link as a QR code
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PaymentLinkQrPayload {
+    /// Identifier for Payment Link
+    pub payment_link_id: String,
+    /// Open payment link (without any security checks and listing SPMs)
+    pub link_to_pay: String,
+    /// The payment amount. Amount for the payment in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+    #[schema(value_type = Option<Currency>)]
+    pub currency: Option<api_enums::Currency>,
+}
+
 #[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
 pub struct PaymentLinkInitiateRequest {
     #[schema(value_type = String)]
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use cards::CardNumber;
 use common_utils::{
     consts::default_payouts_list_limit,
-    crypto, id_type, link_utils, payout_method_utils,
+    crypto, id_type, link_utils, new_type, payout_method_utils,
     pii::{self, Email},
     transformers::ForeignFrom,
     types::{UnifiedCode, UnifiedMessage},
@@ -582,6 +582,36 @@ pub enum PayoutMethodDataResponse {
     Wallet(Box<payout_method_utils::WalletAdditionalData>),
 }
 
+/// Display-safe summary of the payout method, used for payout confirmation screens
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, ToSchema)]
+pub struct PayoutMethodSummary {
+    /// The payout method type
+    #[schema(value_type = PaymentMethodType, example = "debit")]
+    pub payout_method_type: api_enums::PaymentMethodType,
+
+    /// Masked identifier of the payout method
+    pub masked_identifier: Option<PayoutMethodMaskedIdentifier>,
+}
+
+/// Masked identifier of the payout method, which never contains the complete card or account number
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutMethodMaskedIdentifier {
+    /// Last four digits of the card number
+    CardLast4(String),
+    /// Last four characters of the bank account number
+    BankAccountLast4(String),
+    /// Masked email linked to the wallet
+    #[schema(value_type = String)]
+    WalletEmail(new_type::MaskedEmail),
+    /// Masked telephone number linked to the wallet
+    #[schema(value_type = String)]
+    WalletTelephoneNumber(new_type::MaskedPhoneNumber),
+    /// Masked id of the wallet account
+    #[schema(value_type = String)]
+    WalletId(new_type::MaskedBankAccount),
+}
+
 #[derive(
     Default, Debug, serde::Serialize, Clone, PartialEq, ToSchema, router_derive::PolymorphicSchema,
 )]
@@ -280,6 +280,15 @@ pub struct RefundErrorDetails {
     pub message: String,
 }
 
+/// The outgoing webhook event for a refund, along with whether the refund was partial
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct RefundEventDetails {
+    /// The event type to be emitted for the refund
+    pub event_type: enums::EventType,
+    /// Indicates whether the refund amount is lesser than the payment amount
+    pub is_partial_refund: bool,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
 pub struct RefundListRequest {
     /// The identifier for the payment
@@ -167,6 +167,22 @@ pub struct ConnectorVolumeSplit {
     pub split: u8,
 }
 
+/// Preview of the connectors chosen by a connector selection, before the routing algorithm is saved
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RoutingPreview {
+    /// The connectors in the order they are tried, or with the share of the volume they receive
+    pub connectors: Vec<RoutingPreviewConnector>,
+    /// Issues found in the connector selection, which would prevent it from being saved
+    pub warning: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RoutingPreviewConnector {
+    pub connector: RoutableConnectorChoice,
+    /// The percentage of the volume routed to the connector, for a volume split
+    pub split: Option<u8>,
+}
+
 /// Routable Connector chosen for a payment
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
 #[serde(from = "RoutableChoiceSerde", into = "RoutableChoiceSerde")]
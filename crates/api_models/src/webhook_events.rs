@@ -25,6 +25,9 @@ pub struct EventListConstraints {
     /// Refund ID, etc.)
     pub object_id: Option<String>,
 
+    /// Filter events based on whether their delivery was successful.
+    pub is_delivery_successful: Option<bool>,
+
     /// Filter all events associated with the specified business profile ID.
     #[schema(value_type = Option<String>)]
     pub profile_id: Option<common_utils::id_type::ProfileId>,
@@ -37,6 +40,7 @@ pub enum EventListConstraintsInternal {
         created_before: Option<PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        is_delivery_successful: Option<bool>,
     },
     ObjectIdFilter {
         object_id: String,
@@ -71,6 +75,11 @@ pub struct EventListItemResponse {
     /// Indicates whether the webhook delivery attempt was successful.
     pub is_delivery_successful: bool,
 
+    /// The HTTP status code returned by the webhook endpoint. This is `None` if the delivery was
+    /// not attempted.
+    #[schema(example = 200)]
+    pub response_status_code: Option<i16>,
+
     /// The identifier for the initial delivery attempt. This will be the same as `event_id` for
     /// the initial delivery attempt.
     #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
@@ -96,6 +105,12 @@ pub struct EventRetrieveResponse {
 
     /// Indicates the type of delivery attempt.
     pub delivery_attempt: Option<WebhookDeliveryAttempt>,
+
+    /// The total number of delivery attempts made for the event, populated when listing the
+    /// delivery attempts of an event. It is counted from the delivery attempts returned by that
+    /// list, which includes every attempt made for the event.
+    #[schema(example = 1)]
+    pub total_delivery_attempts: Option<i32>,
 }
 
 impl common_utils::events::ApiEventMetric for EventRetrieveResponse {
@@ -120,6 +135,10 @@ pub struct OutgoingWebhookRequestContent {
         example = json!([["content-type", "application/json"], ["content-length", "1024"]]))
     ]
     pub headers: Vec<(String, Secret<String>)>,
+
+    /// The url to which the webhook was sent.
+    #[schema(example = "https://webhook.site/a5e0d35e-d8e5-4b3f-9a2f-8c4b0d2e7f11")]
+    pub url: Option<String>,
 }
 
 /// The response information (headers, body and status code) received for the webhook sent.
@@ -33,6 +33,8 @@ pub enum IncomingWebhookEvent {
     DisputeAccepted,
     DisputeCancelled,
     DisputeChallenged,
+    // challenge submitted by the merchant is being reviewed by the issuer
+    DisputeUnderReview,
     // dispute has been successfully challenged by the merchant
     DisputeWon,
     // dispute has been unsuccessfully challenged
@@ -141,6 +143,7 @@ impl From<IncomingWebhookEvent> for WebhookFlow {
             | IncomingWebhookEvent::DisputeExpired
             | IncomingWebhookEvent::DisputeCancelled
             | IncomingWebhookEvent::DisputeChallenged
+            | IncomingWebhookEvent::DisputeUnderReview
             | IncomingWebhookEvent::DisputeWon
             | IncomingWebhookEvent::DisputeLost => Self::Dispute,
             IncomingWebhookEvent::EndpointVerification => Self::ReturnResponse,
@@ -1182,14 +1182,18 @@ pub enum EventType {
     PaymentCancelled,
     PaymentAuthorized,
     PaymentCaptured,
+    /// Payment created and awaiting payment method or confirmation
+    PaymentCreated,
     ActionRequired,
     RefundSucceeded,
     RefundFailed,
+    RefundProcessing,
     DisputeOpened,
     DisputeExpired,
     DisputeAccepted,
     DisputeCancelled,
     DisputeChallenged,
+    DisputeUnderReview,
     DisputeWon,
     DisputeLost,
     MandateActive,
@@ -1809,12 +1813,39 @@ pub enum DisputeStatus {
     DisputeAccepted,
     DisputeCancelled,
     DisputeChallenged,
+    // challenge submitted by the merchant is being reviewed by the issuer
+    DisputeUnderReview,
     // dispute has been successfully challenged by the merchant
     DisputeWon,
     // dispute has been unsuccessfully challenged
     DisputeLost,
 }
 
+/// Normalized category of the reason for which a dispute was raised
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeCategory {
+    Fraud,
+    ProductNotReceived,
+    ProductUnacceptable,
+    Duplicate,
+    CreditNotProcessed,
+    SubscriptionCanceled,
+}
+
 #[derive(
     Clone,
     Debug,
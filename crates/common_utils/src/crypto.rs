@@ -76,6 +76,17 @@ pub trait SignMessage {
     ) -> CustomResult<Vec<u8>, errors::CryptoError>;
 }
 
+/// The family of algorithm used to verify a signature
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureAlgorithmKind {
+    /// No verification is performed, every signature is accepted
+    NoAlgorithm,
+    /// A keyed hash of the message, computed using the secret
+    KeyedHash,
+    /// A plain digest of the message
+    Digest,
+}
+
 /// Trait for cryptographically verifying a message against a signature
 pub trait VerifySignature {
     /// Takes in a secret, the signature and the message and verifies the message
@@ -86,6 +97,12 @@ pub trait VerifySignature {
         _signature: &[u8],
         _msg: &[u8],
     ) -> CustomResult<bool, errors::CryptoError>;
+
+    /// Returns the family of algorithm used to verify the signature. Most webhook signatures
+    /// are keyed hashes, so that is the default for implementors which do not override it.
+    fn algorithm_kind(&self) -> SignatureAlgorithmKind {
+        SignatureAlgorithmKind::KeyedHash
+    }
 }
 
 /// Trait for cryptographically encoding a message
@@ -132,6 +149,10 @@ impl VerifySignature for NoAlgorithm {
     ) -> CustomResult<bool, errors::CryptoError> {
         Ok(true)
     }
+
+    fn algorithm_kind(&self) -> SignatureAlgorithmKind {
+        SignatureAlgorithmKind::NoAlgorithm
+    }
 }
 
 impl EncodeMessage for NoAlgorithm {
@@ -180,6 +201,10 @@ impl VerifySignature for HmacSha1 {
 
         Ok(hmac::verify(&key, msg, signature).is_ok())
     }
+
+    fn algorithm_kind(&self) -> SignatureAlgorithmKind {
+        SignatureAlgorithmKind::KeyedHash
+    }
 }
 
 /// Represents the HMAC-SHA-256 algorithm
@@ -208,6 +233,10 @@ impl VerifySignature for HmacSha256 {
 
         Ok(hmac::verify(&key, msg, signature).is_ok())
     }
+
+    fn algorithm_kind(&self) -> SignatureAlgorithmKind {
+        SignatureAlgorithmKind::KeyedHash
+    }
 }
 
 /// Represents the HMAC-SHA-512 algorithm
@@ -236,6 +265,10 @@ impl VerifySignature for HmacSha512 {
 
         Ok(hmac::verify(&key, msg, signature).is_ok())
     }
+
+    fn algorithm_kind(&self) -> SignatureAlgorithmKind {
+        SignatureAlgorithmKind::KeyedHash
+    }
 }
 
 ///
@@ -273,6 +306,10 @@ impl VerifySignature for Blake3 {
         let output = blake3::keyed_hash(&key, msg);
         Ok(output.as_bytes() == signature)
     }
+
+    fn algorithm_kind(&self) -> SignatureAlgorithmKind {
+        SignatureAlgorithmKind::KeyedHash
+    }
 }
 
 /// Represents the GCM-AES-256 algorithm
@@ -369,6 +406,10 @@ impl VerifySignature for Sha512 {
         let hashed_digest_into_bytes = hashed_digest.into_bytes();
         Ok(hashed_digest_into_bytes == signature)
     }
+
+    fn algorithm_kind(&self) -> SignatureAlgorithmKind {
+        SignatureAlgorithmKind::Digest
+    }
 }
 /// MD5 hash function
 #[derive(Debug)]
@@ -393,6 +434,10 @@ impl VerifySignature for Md5 {
             .change_context(errors::CryptoError::SignatureVerificationFailed)?;
         Ok(hashed_digest == signature)
     }
+
+    fn algorithm_kind(&self) -> SignatureAlgorithmKind {
+        SignatureAlgorithmKind::Digest
+    }
 }
 
 impl GenerateDigest for Sha256 {
@@ -415,6 +460,10 @@ impl VerifySignature for Sha256 {
         let hashed_digest_into_bytes = hashed_digest.as_slice();
         Ok(hashed_digest_into_bytes == signature)
     }
+
+    fn algorithm_kind(&self) -> SignatureAlgorithmKind {
+        SignatureAlgorithmKind::Digest
+    }
 }
 
 /// Generate a random string using a cryptographically secure pseudo-random number generator
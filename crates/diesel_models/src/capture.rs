@@ -31,6 +31,8 @@ pub struct Capture {
     // reference to the capture at connector side
     pub connector_response_reference_id: Option<String>,
     pub connector_capture_data: Option<String>,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
 }
 
 #[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
@@ -56,6 +58,8 @@ pub struct CaptureNew {
     pub capture_sequence: i16,
     pub connector_response_reference_id: Option<String>,
     pub connector_capture_data: Option<String>,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -56,6 +56,7 @@ impl Event {
         created_before: Option<time::PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        is_delivery_successful: Option<bool>,
     ) -> StorageResult<Vec<Self>> {
         use async_bb8_diesel::AsyncRunQueryDsl;
         use diesel::{debug_query, pg::Pg, QueryDsl};
@@ -83,6 +84,10 @@ impl Event {
             query = query.filter(dsl::created_at.le(created_before));
         }
 
+        if let Some(is_delivery_successful) = is_delivery_successful {
+            query = query.filter(dsl::is_webhook_notified.eq(is_delivery_successful));
+        }
+
         if let Some(limit) = limit {
             query = query.limit(limit);
         }
@@ -142,6 +147,7 @@ impl Event {
         created_before: Option<time::PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        is_delivery_successful: Option<bool>,
     ) -> StorageResult<Vec<Self>> {
         use async_bb8_diesel::AsyncRunQueryDsl;
         use diesel::{debug_query, pg::Pg, QueryDsl};
@@ -169,6 +175,10 @@ impl Event {
             query = query.filter(dsl::created_at.le(created_before));
         }
 
+        if let Some(is_delivery_successful) = is_delivery_successful {
+            query = query.filter(dsl::is_webhook_notified.eq(is_delivery_successful));
+        }
+
         if let Some(limit) = limit {
             query = query.limit(limit);
         }
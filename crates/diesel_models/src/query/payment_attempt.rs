@@ -245,6 +245,29 @@ impl PaymentAttempt {
         .await
     }
 
+    #[cfg(feature = "v1")]
+    pub async fn find_by_merchant_id_attempt_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        attempt_ids: &[String],
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::attempt_id.eq_any(attempt_ids.to_owned())),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
     #[cfg(feature = "v1")]
     pub async fn find_by_merchant_id_payment_id(
         conn: &PgPooledConn,
@@ -222,6 +222,14 @@ impl From<OnlineMandate> for ApiOnlineMandate {
     }
 }
 
+impl MandateData {
+    /// Whether this updates the payment method details of an existing mandate, rather than
+    /// creating a new one
+    pub fn is_update(&self) -> bool {
+        self.update_mandate_id.is_some()
+    }
+}
+
 impl CustomerAcceptance {
     pub fn get_ip_address(&self) -> Option<String> {
         self.online
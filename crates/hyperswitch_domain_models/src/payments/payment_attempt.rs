@@ -142,6 +142,14 @@ pub trait PaymentAttemptInterface {
         storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<Vec<PaymentAttempt>, errors::StorageError>;
 
+    #[cfg(feature = "v1")]
+    async fn find_attempts_by_merchant_id_attempt_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        attempt_ids: &[String],
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PaymentAttempt>, errors::StorageError>;
+
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn get_filters_for_payments(
         &self,
@@ -218,7 +218,7 @@ impl From<api_models::disputes::DisputeResponse> for StripeDisputeResponse {
     fn from(res: api_models::disputes::DisputeResponse) -> Self {
         Self {
             id: res.dispute_id,
-            amount: res.amount,
+            amount: res.amount.to_string(),
             currency: res.currency,
             payment_intent: res.payment_id,
             reason: res.connector_reason,
@@ -256,6 +256,7 @@ impl From<DisputeStatus> for StripeDisputeStatus {
             DisputeStatus::DisputeAccepted => Self::Lost,
             DisputeStatus::DisputeCancelled => Self::WarningClosed,
             DisputeStatus::DisputeChallenged => Self::WarningUnderReview,
+            DisputeStatus::DisputeUnderReview => Self::UnderReview,
             DisputeStatus::DisputeWon => Self::Won,
             DisputeStatus::DisputeLost => Self::Lost,
         }
@@ -268,16 +269,19 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PaymentFailed => "payment_intent.payment_failed",
         api_models::enums::EventType::PaymentProcessing => "payment_intent.processing",
         api_models::enums::EventType::PaymentCancelled => "payment_intent.canceled",
+        api_models::enums::EventType::PaymentCreated => "payment_intent.created",
 
         // the below are not really stripe compatible because stripe doesn't provide this
         api_models::enums::EventType::ActionRequired => "action.required",
         api_models::enums::EventType::RefundSucceeded => "refund.succeeded",
         api_models::enums::EventType::RefundFailed => "refund.failed",
+        api_models::enums::EventType::RefundProcessing => "refund.processing",
         api_models::enums::EventType::DisputeOpened => "dispute.failed",
         api_models::enums::EventType::DisputeExpired => "dispute.expired",
         api_models::enums::EventType::DisputeAccepted => "dispute.accepted",
         api_models::enums::EventType::DisputeCancelled => "dispute.cancelled",
         api_models::enums::EventType::DisputeChallenged => "dispute.challenged",
+        api_models::enums::EventType::DisputeUnderReview => "dispute.under_review",
         api_models::enums::EventType::DisputeWon => "dispute.won",
         api_models::enums::EventType::DisputeLost => "dispute.lost",
         api_models::enums::EventType::MandateActive => "mandate.active",
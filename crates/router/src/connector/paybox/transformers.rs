@@ -17,7 +17,7 @@ use crate::{
         self, PaymentsAuthorizeRequestData, PaymentsCompleteAuthorizeRequestData, RouterData,
     },
     core::errors,
-    types::{self, api, domain, storage::enums, MandateReference},
+    types::{self, api, domain, storage::enums, transformers::ForeignFrom, MandateReference},
 };
 
 pub struct PayboxRouterData<T> {
@@ -862,16 +862,21 @@ impl From<PayboxStatus> for common_enums::RefundStatus {
         }
     }
 }
-impl From<PayboxStatus> for enums::AttemptStatus {
+impl From<PayboxStatus> for types::ConnectorResponseStatus {
     fn from(item: PayboxStatus) -> Self {
         match item {
             PayboxStatus::Cancelled => Self::Voided,
             PayboxStatus::Authorised => Self::Authorized,
-            PayboxStatus::Captured | PayboxStatus::Refunded => Self::Charged,
-            PayboxStatus::Rejected => Self::Failure,
+            PayboxStatus::Captured | PayboxStatus::Refunded => Self::Approved,
+            PayboxStatus::Rejected => Self::Declined,
         }
     }
 }
+impl From<PayboxStatus> for enums::AttemptStatus {
+    fn from(item: PayboxStatus) -> Self {
+        Self::foreign_from(types::ConnectorResponseStatus::from(item))
+    }
+}
 fn get_status_of_request(item: String) -> bool {
     item == *SUCCESS_CODE
 }
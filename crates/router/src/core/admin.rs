@@ -119,17 +119,21 @@ pub async fn create_organization(
     state: SessionState,
     req: api::OrganizationCreateRequest,
 ) -> RouterResponse<api::OrganizationResponse> {
-    let db_organization = ForeignFrom::foreign_from(req);
-    state
+    let db_organization = diesel_models::organization::OrganizationNew::foreign_from(req);
+    let org_created_event = types::OrgCreatedEvent::foreign_from(&db_organization);
+    let organization = state
         .store
         .insert_organization(db_organization)
         .await
         .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
             message: "Organization with the given organization_name already exists".to_string(),
         })
-        .attach_printable("Error when creating organization")
-        .map(ForeignFrom::foreign_from)
-        .map(service_api::ApplicationResponse::Json)
+        .attach_printable("Error when creating organization")?;
+    crate::logger::info!(?org_created_event, "Organization created");
+
+    Ok(service_api::ApplicationResponse::Json(
+        ForeignFrom::foreign_from(organization),
+    ))
 }
 
 #[cfg(feature = "olap")]
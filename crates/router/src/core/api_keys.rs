@@ -3,7 +3,7 @@ use common_utils::date_time;
 use diesel_models::{api_keys::ApiKey, enums as storage_enums};
 use error_stack::{report, ResultExt};
 use masking::{PeekInterface, StrongSecret};
-use router_env::{instrument, metrics::add_attributes, tracing};
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
 
 use crate::{
     configs::settings,
@@ -12,7 +12,7 @@ use crate::{
     db::domain,
     routes::{metrics, SessionState},
     services::{authentication, ApplicationResponse},
-    types::{api, storage, transformers::ForeignInto},
+    types::{self, api, storage, transformers::ForeignInto},
 };
 
 #[cfg(feature = "email")]
@@ -267,6 +267,10 @@ pub async fn retrieve_api_key(
         .attach_printable("Failed to retrieve API key")?
         .ok_or(report!(errors::ApiErrorResponse::ApiKeyNotFound))?; // If retrieve returned `None`
 
+    let audit_log_entry: types::AuditLogEntry =
+        (&api_key, types::AuditAction::Retrieve).foreign_into();
+    logger::info!(?audit_log_entry, "API key retrieved");
+
     Ok(ApplicationResponse::Json(api_key.foreign_into()))
 }
 
@@ -288,6 +292,10 @@ pub async fn update_api_key(
         .await
         .to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound)?;
 
+    let audit_log_entry: types::AuditLogEntry =
+        (&api_key, types::AuditAction::Update).foreign_into();
+    logger::info!(?audit_log_entry, "API key updated");
+
     let state_inner = state.clone();
     let hashed_api_key = api_key.hashed_api_key.clone();
     let key_id_inner = api_key.key_id.clone();
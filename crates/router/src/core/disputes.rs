@@ -42,10 +42,44 @@ pub async fn retrieve_dispute(
             dispute_id: req.dispute_id,
         })?;
     core_utils::validate_profile_id_from_auth_layer(profile_id, &dispute)?;
+
+    #[cfg(feature = "v1")]
+    let dispute_response = {
+        let payment_attempt =
+            find_disputed_payment_attempt(&state, &merchant_account, &dispute).await?;
+        api_models::disputes::DisputeResponse::foreign_from((dispute, payment_attempt.as_ref()))
+    };
+    #[cfg(feature = "v2")]
     let dispute_response = api_models::disputes::DisputeResponse::foreign_from(dispute);
+
     Ok(services::ApplicationResponse::Json(dispute_response))
 }
 
+/// Finds the payment attempt a dispute was raised against, returning `None` when the attempt
+/// no longer exists
+#[cfg(feature = "v1")]
+async fn find_disputed_payment_attempt(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    dispute: &crate::types::storage::Dispute,
+) -> errors::RouterResult<Option<crate::types::storage::PaymentAttempt>> {
+    match state
+        .store
+        .find_payment_attempt_by_attempt_id_merchant_id(
+            &dispute.attempt_id,
+            merchant_account.get_id(),
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(payment_attempt) => Ok(Some(payment_attempt)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the disputed payment attempt")),
+    }
+}
+
 #[instrument(skip(state))]
 pub async fn retrieve_disputes_list(
     state: SessionState,
@@ -60,6 +94,35 @@ pub async fn retrieve_disputes_list(
         .await
         .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Unable to retrieve disputes")?;
+    #[cfg(feature = "v1")]
+    let disputes_list = {
+        let attempt_ids = disputes
+            .iter()
+            .map(|dispute| dispute.attempt_id.clone())
+            .collect::<Vec<_>>();
+        let payment_attempts = state
+            .store
+            .find_attempts_by_merchant_id_attempt_ids(
+                merchant_account.get_id(),
+                &attempt_ids,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to retrieve the disputed payment attempts")?;
+        let payment_attempts = payment_attempts
+            .iter()
+            .map(|payment_attempt| (payment_attempt.attempt_id.as_str(), payment_attempt))
+            .collect::<HashMap<_, _>>();
+        disputes
+            .into_iter()
+            .map(|dispute| {
+                let payment_attempt = payment_attempts.get(dispute.attempt_id.as_str()).copied();
+                api_models::disputes::DisputeResponse::foreign_from((dispute, payment_attempt))
+            })
+            .collect()
+    };
+    #[cfg(feature = "v2")]
     let disputes_list = disputes
         .into_iter()
         .map(api_models::disputes::DisputeResponse::foreign_from)
@@ -235,7 +298,10 @@ pub async fn accept_dispute(
         .attach_printable_lazy(|| {
             format!("Unable to update dispute with dispute_id: {dispute_id}")
         })?;
-    let dispute_response = api_models::disputes::DisputeResponse::foreign_from(updated_dispute);
+    let dispute_response = api_models::disputes::DisputeResponse::foreign_from((
+        updated_dispute,
+        Some(&payment_attempt),
+    ));
     Ok(services::ApplicationResponse::Json(dispute_response))
 }
 
@@ -418,7 +484,10 @@ pub async fn submit_evidence(
         .attach_printable_lazy(|| {
             format!("Unable to update dispute with dispute_id: {dispute_id}")
         })?;
-    let dispute_response = api_models::disputes::DisputeResponse::foreign_from(updated_dispute);
+    let dispute_response = api_models::disputes::DisputeResponse::foreign_from((
+        updated_dispute,
+        Some(&payment_attempt),
+    ));
     Ok(services::ApplicationResponse::Json(dispute_response))
 }
 
@@ -10,7 +10,7 @@ use crate::{
     },
     db::gsm::GsmInterface,
     services,
-    types::transformers::ForeignInto,
+    types::transformers::{ForeignInto, ForeignTryInto},
     SessionState,
 };
 
@@ -25,7 +25,8 @@ pub async fn create_gsm_rule(
         .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
             message: "GSM with given key already exists in our records".to_string(),
         })
-        .map(|gsm| services::ApplicationResponse::Json(gsm.foreign_into()))
+        .and_then(|gsm| gsm.foreign_try_into())
+        .map(services::ApplicationResponse::Json)
 }
 
 #[instrument(skip_all)]
@@ -46,7 +47,8 @@ pub async fn retrieve_gsm_rule(
         .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
             message: "GSM with given key does not exist in our records".to_string(),
         })
-        .map(|gsm| services::ApplicationResponse::Json(gsm.foreign_into()))
+        .and_then(|gsm| gsm.foreign_try_into())
+        .map(services::ApplicationResponse::Json)
 }
 
 #[instrument(skip_all)]
@@ -89,7 +91,8 @@ pub async fn update_gsm_rule(
         message: "GSM with given key does not exist in our records".to_string(),
     })
     .attach_printable("Failed while updating Gsm rule")
-    .map(|gsm| services::ApplicationResponse::Json(gsm.foreign_into()))
+    .and_then(|gsm| gsm.foreign_try_into())
+    .map(services::ApplicationResponse::Json)
 }
 
 #[instrument(skip_all)]
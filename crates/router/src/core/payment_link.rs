@@ -49,17 +49,8 @@ pub async fn retrieve_payment_link(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
 
-    let session_expiry = payment_link_config.fulfilment_time.unwrap_or_else(|| {
-        common_utils::date_time::now()
-            .saturating_add(time::Duration::seconds(DEFAULT_SESSION_EXPIRY))
-    });
-
-    let status = check_payment_link_status(session_expiry);
-
-    let response = api_models::payments::RetrievePaymentLinkResponse::foreign_from((
-        payment_link_config,
-        status,
-    ));
+    let response =
+        api_models::payments::RetrievePaymentLinkResponse::foreign_from(payment_link_config);
     Ok(services::ApplicationResponse::Json(response))
 }
 
@@ -78,7 +78,7 @@ use crate::{
         api::{self, admin, enums as api_enums, MandateValidationFieldsExt},
         domain::{self, types},
         storage::{self, enums as storage_enums, ephemeral_key, CardTokenData},
-        transformers::{ForeignFrom, ForeignTryFrom},
+        transformers::ForeignTryFrom,
         AdditionalMerchantData, AdditionalPaymentMethodConnectorResponse, ErrorResponse,
         MandateReference, MerchantAccountData, MerchantRecipientData, PaymentsResponseData,
         RecipientIdType, RecurringMandatePaymentData, RouterData,
@@ -398,7 +398,14 @@ pub async fn get_token_pm_type_mandate_details(
     payment_method_id: Option<String>,
     payment_intent_customer_id: Option<&id_type::CustomerId>,
 ) -> RouterResult<MandateGenericData> {
-    let mandate_data = request.mandate_data.clone().map(MandateData::foreign_from);
+    let mandate_data = request
+        .mandate_data
+        .clone()
+        .map(|mandate_data| MandateData::foreign_try_from((mandate_data, request.currency)))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "mandate_data",
+        })?;
     let (
         payment_token,
         payment_method,
@@ -24,7 +24,7 @@ use crate::{
         metrics,
     },
     services,
-    types::{self, api, domain, storage},
+    types::{self, api, domain, storage, transformers::ForeignFrom},
 };
 
 #[instrument(skip_all)]
@@ -712,33 +712,10 @@ impl<F: Send + Clone + Sync, FData: Send + Sync>
         if self.response.is_err() {
             true
         } else {
-            match self.status {
-                storage_enums::AttemptStatus::Started
-                | storage_enums::AttemptStatus::AuthenticationPending
-                | storage_enums::AttemptStatus::AuthenticationSuccessful
-                | storage_enums::AttemptStatus::Authorized
-                | storage_enums::AttemptStatus::Charged
-                | storage_enums::AttemptStatus::Authorizing
-                | storage_enums::AttemptStatus::CodInitiated
-                | storage_enums::AttemptStatus::Voided
-                | storage_enums::AttemptStatus::VoidInitiated
-                | storage_enums::AttemptStatus::CaptureInitiated
-                | storage_enums::AttemptStatus::RouterDeclined
-                | storage_enums::AttemptStatus::VoidFailed
-                | storage_enums::AttemptStatus::AutoRefunded
-                | storage_enums::AttemptStatus::CaptureFailed
-                | storage_enums::AttemptStatus::PartialCharged
-                | storage_enums::AttemptStatus::PartialChargedAndChargeable
-                | storage_enums::AttemptStatus::Pending
-                | storage_enums::AttemptStatus::PaymentMethodAwaited
-                | storage_enums::AttemptStatus::ConfirmationAwaited
-                | storage_enums::AttemptStatus::Unresolved
-                | storage_enums::AttemptStatus::DeviceDataCollectionPending => false,
-
-                storage_enums::AttemptStatus::AuthenticationFailed
-                | storage_enums::AttemptStatus::AuthorizationFailed
-                | storage_enums::AttemptStatus::Failure => true,
-            }
+            matches!(
+                types::RetryEligibility::foreign_from(self.status),
+                types::RetryEligibility::Retryable
+            )
         }
     }
 }
@@ -61,6 +61,15 @@ pub fn validate_refund_amount(
         })
         .sum();
 
+    validate_refundable_amount(amount_captured, total_refunded_amount, refund_amount)
+}
+
+/// Validates the refund amount against the amount of the payment which is yet to be refunded
+pub fn validate_refundable_amount(
+    amount_captured: i64,
+    total_refunded_amount: i64,
+    refund_amount: i64,
+) -> CustomResult<(), RefundValidationError> {
     utils::when(
         refund_amount > (amount_captured - total_refunded_amount),
         || {
@@ -207,6 +207,7 @@ pub async fn create_routing_algorithm_under_profile(
     };
 
     algorithm_helper.validate_connectors_in_routing_config()?;
+    helpers::validate_volume_splits_in_routing_config(&request.algorithm)?;
 
     let algo = RoutingAlgorithmUpdate::create_new_routing_algorithm(
         &request,
@@ -293,6 +294,7 @@ pub async fn create_routing_algorithm_under_profile(
         &algorithm,
     )
     .await?;
+    helpers::validate_volume_splits_in_routing_config(&algorithm)?;
 
     let timestamp = common_utils::date_time::now();
     let algo = RoutingAlgorithm {
@@ -32,7 +32,7 @@ use crate::{
     core::errors::{self, RouterResult},
     db::StorageInterface,
     routes::SessionState,
-    types::{domain, storage},
+    types::{domain, storage, transformers::ForeignTryFrom},
     utils::StringExt,
 };
 #[cfg(feature = "v1")]
@@ -436,6 +436,43 @@ impl<'h> RoutingAlgorithmHelpers<'h> {
     }
 }
 
+/// Rejects volume splits, including those in the rules of an advanced algorithm, whose split
+/// percentages do not add up to 100
+pub fn validate_volume_splits_in_routing_config(
+    routing_algorithm: &routing_types::RoutingAlgorithm,
+) -> RouterResult<()> {
+    let validate_connector_selection =
+        |selection: &routing_types::ConnectorSelection| -> RouterResult<()> {
+            routing_types::RoutingAlgorithm::foreign_try_from(selection.clone())
+                .map(|_| ())
+                .map_err(|error| {
+                    let message = error.current_context().to_string();
+                    error.change_context(errors::ApiErrorResponse::InvalidRequestData { message })
+                })
+        };
+
+    match routing_algorithm {
+        routing_types::RoutingAlgorithm::Single(_)
+        | routing_types::RoutingAlgorithm::Priority(_) => {}
+
+        routing_types::RoutingAlgorithm::VolumeSplit(splits) => {
+            validate_connector_selection(&routing_types::ConnectorSelection::VolumeSplit(
+                splits.clone(),
+            ))?;
+        }
+
+        routing_types::RoutingAlgorithm::Advanced(program) => {
+            validate_connector_selection(&program.default_selection)?;
+
+            for rule in &program.rules {
+                validate_connector_selection(&rule.connector_selection)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(feature = "v1")]
 pub async fn validate_connectors_in_routing_config(
     state: &SessionState,
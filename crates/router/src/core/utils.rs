@@ -42,6 +42,7 @@ use crate::{
     types::{
         self, api, domain,
         storage::{self, enums},
+        transformers::ForeignTryFrom,
         PollConfig,
     },
     utils::{generate_id, generate_uuid, OptionExt, ValueExt},
@@ -556,14 +557,10 @@ pub fn validate_dispute_stage(
     prev_dispute_stage: &DisputeStage,
     dispute_stage: &DisputeStage,
 ) -> bool {
-    match prev_dispute_stage {
-        DisputeStage::PreDispute => true,
-        DisputeStage::Dispute => !matches!(dispute_stage, DisputeStage::PreDispute),
-        DisputeStage::PreArbitration => matches!(dispute_stage, DisputeStage::PreArbitration),
-    }
+    DisputeStage::foreign_try_from((*prev_dispute_stage, *dispute_stage)).is_ok()
 }
 
-//Dispute status can go from Opened -> (Expired | Accepted | Cancelled | Challenged -> (Won | Lost))
+//Dispute status can go from Opened -> (Expired | Accepted | Cancelled | Challenged -> UnderReview -> (Won | Lost))
 pub fn validate_dispute_status(
     prev_dispute_status: DisputeStatus,
     dispute_status: DisputeStatus,
@@ -582,6 +579,13 @@ pub fn validate_dispute_status(
         DisputeStatus::DisputeChallenged => matches!(
             dispute_status,
             DisputeStatus::DisputeChallenged
+                | DisputeStatus::DisputeUnderReview
+                | DisputeStatus::DisputeWon
+                | DisputeStatus::DisputeLost
+        ),
+        DisputeStatus::DisputeUnderReview => matches!(
+            dispute_status,
+            DisputeStatus::DisputeUnderReview
                 | DisputeStatus::DisputeWon
                 | DisputeStatus::DisputeLost
         ),
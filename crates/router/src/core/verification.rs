@@ -15,6 +15,8 @@ pub async fn verify_merchant_creds_for_applepay(
     profile_id: Option<common_utils::id_type::ProfileId>,
 ) -> CustomResult<services::ApplicationResponse<ApplepayMerchantResponse>, errors::ApiErrorResponse>
 {
+    utils::validate_applepay_verified_domains(&body.domain_names)?;
+
     let applepay_merchant_configs = state.conf.applepay_merchant_configs.get_inner();
 
     let applepay_internal_merchant_identifier = applepay_merchant_configs
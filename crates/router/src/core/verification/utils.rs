@@ -160,3 +160,50 @@ pub async fn check_if_profile_id_is_present_in_payment_intent(
 
     utils::validate_profile_id_from_auth_layer(auth_data.profile_id.clone(), &payment_intent)
 }
+
+/// Apple Pay rejects verified domains which are not plain domain names (such as urls or ip
+/// addresses), so they are validated before being sent for verification.
+pub fn validate_applepay_verified_domains(domain_names: &[String]) -> errors::RouterResult<()> {
+    if let Some(invalid_domain) = domain_names
+        .iter()
+        .find(|domain| !matches!(url::Host::parse(domain), Ok(url::Host::Domain(_))))
+    {
+        return Err(error_stack::report!(
+            errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "domain_names".to_string(),
+                expected_format: format!(
+                    "a list of domain names such as example.com, found {invalid_domain}"
+                ),
+            }
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_validate_applepay_verified_domains() {
+        assert!(validate_applepay_verified_domains(&[
+            "example.com".to_string(),
+            "checkout.example.co.uk".to_string(),
+        ])
+        .is_ok());
+
+        for invalid_domain in ["https://example.com/pay", "exa mple.com", "127.0.0.1"] {
+            let error = validate_applepay_verified_domains(&[
+                "example.com".to_string(),
+                invalid_domain.to_string(),
+            ])
+            .unwrap_err();
+            assert!(matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::InvalidDataFormat { field_name, expected_format }
+                    if field_name == "domain_names" && expected_format.ends_with(invalid_domain)
+            ));
+        }
+    }
+}
@@ -1399,8 +1399,10 @@ async fn disputes_incoming_webhook_flow(
             connector.id(),
         )
         .await?;
-        let disputes_response = Box::new(dispute_object.clone().foreign_into());
-        let event_type: enums::EventType = dispute_object.dispute_status.foreign_into();
+        let (event_type, disputes_response): (
+            enums::EventType,
+            api_models::disputes::DisputeResponse,
+        ) = (dispute_object.clone(), Some(&payment_attempt)).foreign_into();
 
         Box::pin(super::create_event_and_trigger_outgoing_webhook(
             state,
@@ -1411,7 +1413,7 @@ async fn disputes_incoming_webhook_flow(
             enums::EventClass::Disputes,
             dispute_object.dispute_id.clone(),
             enums::EventObjectType::DisputeDetails,
-            api::OutgoingWebhookContent::DisputeDetails(disputes_response),
+            api::OutgoingWebhookContent::DisputeDetails(Box::new(disputes_response)),
             Some(dispute_object.created_at),
         ))
         .await?;
@@ -651,6 +651,8 @@ pub(crate) fn get_outgoing_webhook_request(
                 .into_iter()
                 .map(|(name, value)| (name, Secret::new(value.into_inner())))
                 .collect(),
+            // A missing webhook url is reported when the webhook is sent
+            url: get_webhook_url_from_business_profile(business_profile).ok(),
         })
     }
 
@@ -57,6 +57,7 @@ pub async fn list_initial_delivery_attempts(
             created_before,
             limit,
             offset,
+            is_delivery_successful,
         } => {
             let limit = match limit {
                 Some(limit) if  limit <= INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT => Ok(Some(limit)),
@@ -80,6 +81,7 @@ pub async fn list_initial_delivery_attempts(
                     created_before,
                     limit,
                     offset,
+                    is_delivery_successful,
                     &key_store,
                 )
                 .await,
@@ -90,6 +92,7 @@ pub async fn list_initial_delivery_attempts(
                     created_before,
                     limit,
                     offset,
+                    is_delivery_successful,
                     &key_store,
                 )
                 .await,
@@ -142,12 +145,9 @@ pub async fn list_delivery_attempts(
         ))
         .attach_printable("No delivery attempts found with the specified `initial_attempt_id`")
     } else {
-        Ok(ApplicationResponse::Json(
-            events
-                .into_iter()
-                .map(api::webhook_events::EventRetrieveResponse::try_from)
-                .collect::<Result<Vec<_>, _>>()?,
-        ))
+        let delivery_attempts =
+            Vec::<api::webhook_events::EventRetrieveResponse>::foreign_try_from(events)?;
+        Ok(ApplicationResponse::Json(delivery_attempts))
     }
 }
 
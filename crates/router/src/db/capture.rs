@@ -199,6 +199,8 @@ impl CaptureInterface for MockDb {
             connector_capture_id: capture.connector_capture_id,
             connector_response_reference_id: capture.connector_response_reference_id,
             connector_capture_data: capture.connector_capture_data,
+            profile_id: capture.profile_id,
+            merchant_connector_id: capture.merchant_connector_id,
         };
         captures.push(capture.clone());
         Ok(capture)
@@ -53,6 +53,7 @@ where
         created_before: Option<time::PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        is_delivery_successful: Option<bool>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
 
@@ -81,6 +82,7 @@ where
         created_before: Option<time::PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        is_delivery_successful: Option<bool>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
 
@@ -185,6 +187,7 @@ impl EventInterface for Store {
         created_before: Option<time::PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        is_delivery_successful: Option<bool>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let conn = connection::pg_connection_read(self).await?;
@@ -195,6 +198,7 @@ impl EventInterface for Store {
             created_before,
             limit,
             offset,
+            is_delivery_successful,
         )
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
@@ -296,6 +300,7 @@ impl EventInterface for Store {
         created_before: Option<time::PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        is_delivery_successful: Option<bool>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let conn = connection::pg_connection_read(self).await?;
@@ -306,6 +311,7 @@ impl EventInterface for Store {
             created_before,
             limit,
             offset,
+            is_delivery_successful,
         )
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
@@ -456,6 +462,7 @@ impl EventInterface for MockDb {
         created_before: Option<time::PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        is_delivery_successful: Option<bool>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let locked_events = self.events.lock().await;
@@ -471,6 +478,10 @@ impl EventInterface for MockDb {
                 check = check && (event.created_at <= created_before);
             }
 
+            if let Some(is_delivery_successful) = is_delivery_successful {
+                check = check && (event.is_webhook_notified == is_delivery_successful);
+            }
+
             check
         });
 
@@ -594,6 +605,7 @@ impl EventInterface for MockDb {
         created_before: Option<time::PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        is_delivery_successful: Option<bool>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let locked_events = self.events.lock().await;
@@ -609,6 +621,10 @@ impl EventInterface for MockDb {
                 check = check && (event.created_at <= created_before);
             }
 
+            if let Some(is_delivery_successful) = is_delivery_successful {
+                check = check && (event.is_webhook_notified == is_delivery_successful);
+            }
+
             check
         });
 
@@ -713,6 +713,7 @@ impl EventInterface for KafkaStore {
         created_before: Option<PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        is_delivery_successful: Option<bool>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         self.diesel_store
@@ -723,6 +724,7 @@ impl EventInterface for KafkaStore {
                 created_before,
                 limit,
                 offset,
+                is_delivery_successful,
                 merchant_key_store,
             )
             .await
@@ -770,6 +772,7 @@ impl EventInterface for KafkaStore {
         created_before: Option<PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        is_delivery_successful: Option<bool>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         self.diesel_store
@@ -780,6 +783,7 @@ impl EventInterface for KafkaStore {
                 created_before,
                 limit,
                 offset,
+                is_delivery_successful,
                 merchant_key_store,
             )
             .await
@@ -1630,6 +1634,18 @@ impl PaymentAttemptInterface for KafkaStore {
             .find_attempts_by_merchant_id_payment_id(merchant_id, payment_id, storage_scheme)
             .await
     }
+
+    #[cfg(feature = "v1")]
+    async fn find_attempts_by_merchant_id_attempt_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        attempt_ids: &[String],
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage::PaymentAttempt>, errors::DataStorageError> {
+        self.diesel_store
+            .find_attempts_by_merchant_id_attempt_ids(merchant_id, attempt_ids, storage_scheme)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
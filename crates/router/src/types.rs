@@ -493,6 +493,165 @@ pub struct PaymentMethodTokenResult {
     pub is_payment_method_tokenization_performed: bool,
 }
 
+/// Fields of the payment method data that are relevant for computing a fingerprint
+#[derive(Debug, Clone)]
+pub enum FingerprintInput {
+    Card(cards::CardNumber),
+    Iban(Secret<String>),
+    BankAccount {
+        account_number: Secret<String>,
+        /// Routing number, BSB number or sort code, depending on the bank debit type
+        bank_code: Secret<String>,
+    },
+}
+
+/// Normalized status of a payment as reported by a connector, which connectors can map their
+/// raw statuses into before converting them to an `AttemptStatus`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectorResponseStatus {
+    /// Payment has been authorized and captured
+    Approved,
+    Declined,
+    Pending,
+    /// Payment has been authorized but not captured
+    Authorized,
+    Voided,
+}
+
+/// Identifying details of a merchant connector account which are safe to attach to logs and
+/// errors, as they leave out the connector account details and other secrets
+#[derive(Debug, Clone)]
+pub struct SafeConnectorDebug {
+    pub connector_name: String,
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    pub connector_type: common_enums::ConnectorType,
+}
+
+/// An outgoing webhook reconstructed from a stored event, which can be sent again as is
+#[derive(Debug, Clone)]
+pub struct ReplayableWebhookRequest {
+    pub url: String,
+    pub headers: Vec<(String, Secret<String>)>,
+    pub body: Secret<String>,
+}
+
+/// Action performed on a resource, recorded in an audit log entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditAction {
+    Retrieve,
+    Update,
+}
+
+/// Audit record of an action performed on an API key. It must never contain key material.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct AuditLogEntry {
+    pub key_id: common_utils::id_type::ApiKeyId,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub action: AuditAction,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub timestamp: time::PrimitiveDateTime,
+}
+
+/// Internal event emitted when an organization is created
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct OrgCreatedEvent {
+    pub org_id: common_utils::id_type::OrganizationId,
+    pub org_name: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+/// Whether a payment can be retried with a new attempt, based on the status of the current attempt
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryEligibility {
+    /// The attempt failed, and a new attempt may succeed
+    Retryable,
+    /// The attempt reached a state which a new attempt cannot improve upon
+    Terminal,
+    /// The attempt has not reached a final state yet
+    InProgress,
+}
+
+/// A flat representation of a refund, used for exporting refunds to a ledger
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RefundLedgerRow {
+    pub refund_id: String,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub amount: MinorUnit,
+    pub currency: common_enums::Currency,
+    pub connector: String,
+    pub status: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+/// The way in which the source of an incoming webhook from a connector is verified
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebhookVerificationScheme {
+    /// An HMAC of the payload, computed using the webhook secret
+    Hmac,
+    /// A plain digest of the payload and the webhook secret, sent in a header
+    SignatureHeader,
+    /// The connector does not sign its webhooks, so their source is not verified
+    Unverified,
+}
+
+/// Currencies that a connector is able to process payments in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConnectorCurrencySupport {
+    /// The connector does not restrict the currency of the payment
+    All,
+    /// The connector processes payments only in the listed currencies
+    Only(std::collections::HashSet<common_enums::Currency>),
+}
+
+impl ConnectorCurrencySupport {
+    pub fn is_supported(&self, currency: common_enums::Currency) -> bool {
+        match self {
+            Self::All => true,
+            Self::Only(currencies) => currencies.contains(&currency),
+        }
+    }
+}
+
+/// Details of a customer with the personal information masked, safe to be logged
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaskedCustomerDetails {
+    pub customer_id: common_utils::id_type::CustomerId,
+    /// Email with only the first character of the local part retained, as `j***@domain.com`
+    pub email: Option<String>,
+    /// Phone number with only the last four digits retained
+    pub phone: Option<String>,
+}
+
+/// A refund request whose amount has been validated against the amount of the payment
+#[derive(Debug, Clone)]
+pub struct ValidatedRefund {
+    pub request: api_models::refunds::RefundRequest,
+    /// The amount to be refunded, which is the full payment amount when not specified in the request
+    pub amount: MinorUnit,
+}
+
+/// The window in which a refund is expected to settle with the customer, for display purposes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefundTimingHint {
+    /// The refund settles on the day it is processed
+    SameDay,
+    /// The refund settles within the given range of business days after it is processed
+    BusinessDays { min: u8, max: u8 },
+}
+
+impl std::fmt::Display for RefundTimingHint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SameDay => write!(f, "same day"),
+            Self::BusinessDays { min, max } => write!(f, "T+{min} to T+{max} business days"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum Redirection {
     Redirect,
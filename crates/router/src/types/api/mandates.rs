@@ -90,29 +90,45 @@ impl MandateResponseExt for MandateResponse {
         } else {
             None
         };
-        let payment_method_type = payment_method
-            .payment_method_type
-            .map(|pmt| pmt.to_string());
-        Ok(Self {
-            mandate_id: mandate.mandate_id,
-            customer_acceptance: Some(api::payments::CustomerAcceptance {
-                acceptance_type: if mandate.customer_ip_address.is_some() {
-                    api::payments::AcceptanceType::Online
-                } else {
-                    api::payments::AcceptanceType::Offline
-                },
-                accepted_at: mandate.customer_accepted_at,
-                online: Some(api::payments::OnlineMandate {
-                    ip_address: mandate.customer_ip_address,
-                    user_agent: mandate.customer_user_agent.unwrap_or_default(),
-                }),
+        Ok(build_mandate_response(&mandate, &payment_method, card))
+    }
+}
+
+/// Builds the mandate response from the mandate, its payment method and the card details of the
+/// payment method, if any
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub(crate) fn build_mandate_response(
+    mandate: &storage::Mandate,
+    payment_method: &domain::PaymentMethod,
+    card: Option<mandates::MandateCardDetails>,
+) -> MandateResponse {
+    MandateResponse {
+        mandate_id: mandate.mandate_id.clone(),
+        customer_acceptance: Some(api::payments::CustomerAcceptance {
+            acceptance_type: if mandate.customer_ip_address.is_some() {
+                api::payments::AcceptanceType::Online
+            } else {
+                api::payments::AcceptanceType::Offline
+            },
+            accepted_at: mandate.customer_accepted_at,
+            online: Some(api::payments::OnlineMandate {
+                ip_address: mandate.customer_ip_address.clone(),
+                user_agent: mandate.customer_user_agent.clone().unwrap_or_default(),
             }),
-            card,
-            status: mandate.mandate_status,
-            payment_method: pm.to_string(),
-            payment_method_type,
-            payment_method_id: mandate.payment_method_id,
-        })
+        }),
+        card,
+        status: mandate.mandate_status,
+        payment_method: payment_method
+            .payment_method
+            .map(|pm| pm.to_string())
+            .unwrap_or_default(),
+        payment_method_type: payment_method
+            .payment_method_type
+            .map(|pmt| pmt.to_string()),
+        payment_method_id: mandate.payment_method_id.clone(),
     }
 }
 
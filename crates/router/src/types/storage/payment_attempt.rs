@@ -64,6 +64,8 @@ impl PaymentAttemptExt for PaymentAttempt {
             connector_capture_id: None,
             connector_response_reference_id: None,
             connector_capture_data: None,
+            profile_id: Some(self.profile_id.clone()),
+            merchant_connector_id: self.merchant_connector_id.clone(),
         })
     }
 
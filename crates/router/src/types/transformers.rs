@@ -12,13 +12,14 @@ use common_utils::{
     pii,
     types::ConnectorTransactionIdTrait,
 };
-use diesel_models::enums as storage_enums;
+use diesel_models::{enums as storage_enums, organization::OrganizationBridge};
 use error_stack::{report, ResultExt};
 use hyperswitch_domain_models::payments::payment_intent::CustomerData;
 use masking::{ExposeInterface, PeekInterface, Secret};
 
 use super::domain;
 use crate::{
+    configs::settings,
     core::errors,
     headers::{
         ACCEPT_LANGUAGE, BROWSER_NAME, X_APP_ID, X_CLIENT_PLATFORM, X_CLIENT_SOURCE,
@@ -81,6 +82,16 @@ impl ForeignFrom<api_models::refunds::RefundType> for storage_enums::RefundType
     }
 }
 
+impl ForeignFrom<api_models::refunds::RefundType> for router_types::RefundTimingHint {
+    fn foreign_from(item: api_models::refunds::RefundType) -> Self {
+        match item {
+            api_models::refunds::RefundType::Instant => Self::SameDay,
+            // Scheduled refunds are settled along with the regular settlement cycle of the connector
+            api_models::refunds::RefundType::Scheduled => Self::BusinessDays { min: 5, max: 10 },
+        }
+    }
+}
+
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
@@ -97,26 +108,112 @@ impl
             domain::PaymentMethod,
         ),
     ) -> Self {
+        // Fallback to the card details stored in the payment methods table when they are not
+        // fetched from the locker
+        let card = card_details.or_else(|| {
+            get_card_details_from_payment_method_data(
+                item.payment_method_data
+                    .clone()
+                    .map(|data| data.into_inner().expose()),
+            )
+            .map(|mut card| {
+                card.scheme.clone_from(&item.scheme);
+                card
+            })
+        });
+        #[cfg(feature = "payouts")]
+        let bank_transfer = get_bank_transfer_details_from_payment_method_data(
+            item.payment_method_data
+                .clone()
+                .map(|data| data.into_inner().expose()),
+        );
+        let recurring_enabled =
+            has_active_connector_mandate(item.connector_mandate_details.as_ref());
         Self {
             merchant_id: item.merchant_id.to_owned(),
             customer_id: Some(item.customer_id.to_owned()),
             payment_method_id: item.get_id().clone(),
             payment_method: item.payment_method,
             payment_method_type: item.payment_method_type,
-            card: card_details,
-            recurring_enabled: false,
+            card,
+            recurring_enabled,
             installment_payment_enabled: false,
             payment_experience: None,
             metadata: item.metadata,
             created: Some(item.created_at),
             #[cfg(feature = "payouts")]
-            bank_transfer: None,
-            last_used_at: None,
+            bank_transfer,
+            last_used_at: Some(item.last_used_at),
             client_secret: item.client_secret,
         }
     }
 }
 
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+fn get_card_details_from_payment_method_data(
+    payment_method_data: Option<serde_json::Value>,
+) -> Option<payment_methods::CardDetailFromLocker> {
+    payment_method_data
+        .and_then(|value| serde_json::from_value::<payment_methods::PaymentMethodsData>(value).ok())
+        .and_then(|payment_methods_data| match payment_methods_data {
+            payment_methods::PaymentMethodsData::Card(card) => {
+                Some(payment_methods::CardDetailFromLocker::from(card))
+            }
+            payment_methods::PaymentMethodsData::BankDetails(_) => None,
+        })
+}
+
+/// Only the masked account number is stored in the payment methods table, so the bank details
+/// carry the last 4 characters of the account number. Bank types which require a routing number
+/// or sort code cannot be represented, as those are not stored.
+#[cfg(all(
+    feature = "payouts",
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+fn get_bank_transfer_details_from_payment_method_data(
+    payment_method_data: Option<serde_json::Value>,
+) -> Option<api_models::payouts::Bank> {
+    payment_method_data
+        .and_then(|value| serde_json::from_value::<payment_methods::PaymentMethodsData>(value).ok())
+        .and_then(|payment_methods_data| match payment_methods_data {
+            payment_methods::PaymentMethodsData::Card(_) => None,
+            payment_methods::PaymentMethodsData::BankDetails(bank_details) => {
+                match bank_details.payment_method_type {
+                    api_enums::PaymentMethodType::Sepa => Some(api_models::payouts::Bank::Sepa(
+                        api_models::payouts::SepaBankTransfer {
+                            bank_name: None,
+                            bank_country_code: None,
+                            bank_city: None,
+                            iban: Secret::new(get_last4_characters(&bank_details.mask)),
+                            bic: None,
+                        },
+                    )),
+                    _ => None,
+                }
+            }
+        })
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+fn has_active_connector_mandate(connector_mandate_details: Option<&serde_json::Value>) -> bool {
+    connector_mandate_details
+        .cloned()
+        .and_then(|value| serde_json::from_value::<storage::PaymentsMandateReference>(value).ok())
+        .is_some_and(|mandate_reference| {
+            mandate_reference.0.values().any(|record| {
+                record.connector_mandate_status
+                    == Some(common_enums::ConnectorMandateStatus::Active)
+            })
+        })
+}
+
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
 impl
     ForeignFrom<(
@@ -174,6 +271,102 @@ impl ForeignFrom<storage_enums::AttemptStatus> for storage_enums::IntentStatus {
     }
 }
 
+impl ForeignFrom<storage_enums::AttemptStatus> for payments::AttemptStatusDisplay {
+    fn foreign_from(status: storage_enums::AttemptStatus) -> Self {
+        let label = match status {
+            storage_enums::AttemptStatus::Started => "Started",
+            storage_enums::AttemptStatus::AuthenticationFailed => "Authentication failed",
+            storage_enums::AttemptStatus::RouterDeclined => "Declined by router",
+            storage_enums::AttemptStatus::AuthenticationPending => "Authentication pending",
+            storage_enums::AttemptStatus::AuthenticationSuccessful => "Authentication successful",
+            storage_enums::AttemptStatus::Authorized => "Authorized, awaiting capture",
+            storage_enums::AttemptStatus::AuthorizationFailed => "Authorization failed",
+            storage_enums::AttemptStatus::Charged => "Captured",
+            storage_enums::AttemptStatus::Authorizing => "Authorizing",
+            storage_enums::AttemptStatus::CodInitiated => "Cash on delivery initiated",
+            storage_enums::AttemptStatus::Voided => "Voided",
+            storage_enums::AttemptStatus::VoidInitiated => "Void initiated",
+            storage_enums::AttemptStatus::CaptureInitiated => "Capture initiated",
+            storage_enums::AttemptStatus::CaptureFailed => "Capture failed",
+            storage_enums::AttemptStatus::VoidFailed => "Void failed",
+            storage_enums::AttemptStatus::AutoRefunded => "Automatically refunded",
+            storage_enums::AttemptStatus::PartialCharged => "Partially captured",
+            storage_enums::AttemptStatus::PartialChargedAndChargeable => {
+                "Partially captured, more capturable"
+            }
+            storage_enums::AttemptStatus::Unresolved => "Unresolved, requires merchant action",
+            storage_enums::AttemptStatus::Pending => "Pending",
+            storage_enums::AttemptStatus::Failure => "Failed",
+            storage_enums::AttemptStatus::PaymentMethodAwaited => "Awaiting payment method",
+            storage_enums::AttemptStatus::ConfirmationAwaited => "Awaiting confirmation",
+            storage_enums::AttemptStatus::DeviceDataCollectionPending => {
+                "Device data collection pending"
+            }
+        };
+        Self {
+            status,
+            label: label.to_string(),
+        }
+    }
+}
+
+impl ForeignTryFrom<storage_enums::IntentStatus> for storage_enums::AttemptStatus {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn foreign_try_from(intent_status: storage_enums::IntentStatus) -> errors::RouterResult<Self> {
+        match intent_status {
+            storage_enums::IntentStatus::Succeeded => Ok(Self::Charged),
+            storage_enums::IntentStatus::Failed => Ok(Self::Failure),
+            storage_enums::IntentStatus::Cancelled => Ok(Self::Voided),
+            storage_enums::IntentStatus::Processing => Ok(Self::Pending),
+            storage_enums::IntentStatus::RequiresCustomerAction => Ok(Self::AuthenticationPending),
+            storage_enums::IntentStatus::RequiresMerchantAction => Ok(Self::Unresolved),
+            storage_enums::IntentStatus::RequiresPaymentMethod => Ok(Self::PaymentMethodAwaited),
+            storage_enums::IntentStatus::RequiresConfirmation => Ok(Self::ConfirmationAwaited),
+            storage_enums::IntentStatus::RequiresCapture => Ok(Self::Authorized),
+
+            // The attempt status for partial captures depends on the individual capture records
+            storage_enums::IntentStatus::PartiallyCaptured
+            | storage_enums::IntentStatus::PartiallyCapturedAndCapturable => {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "IntentStatus cannot be reversed to an AttemptStatus when it is one of [PartiallyCaptured, PartiallyCapturedAndCapturable]".into(),
+                }
+                .into())
+            }
+        }
+    }
+}
+
+/// Validates a dispute stage transition from the current stage (first) to the target stage
+/// (second). Dispute stages can only move forward, PreDispute -> Dispute -> PreArbitration.
+impl ForeignTryFrom<(storage_enums::DisputeStage, storage_enums::DisputeStage)>
+    for storage_enums::DisputeStage
+{
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn foreign_try_from(
+        (current_stage, target_stage): (storage_enums::DisputeStage, storage_enums::DisputeStage),
+    ) -> Result<Self, Self::Error> {
+        let is_valid_transition = match current_stage {
+            storage_enums::DisputeStage::PreDispute => true,
+            storage_enums::DisputeStage::Dispute => {
+                !matches!(target_stage, storage_enums::DisputeStage::PreDispute)
+            }
+            storage_enums::DisputeStage::PreArbitration => {
+                matches!(target_stage, storage_enums::DisputeStage::PreArbitration)
+            }
+        };
+        when(!is_valid_transition, || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Dispute stage cannot be moved from {current_stage} to {target_stage}"
+                ),
+            })
+        })?;
+        Ok(target_stage)
+    }
+}
+
 impl ForeignTryFrom<storage_enums::AttemptStatus> for storage_enums::CaptureStatus {
     type Error = error_stack::Report<errors::ApiErrorResponse>;
 
@@ -214,13 +407,29 @@ impl ForeignTryFrom<storage_enums::AttemptStatus> for storage_enums::CaptureStat
     }
 }
 
-impl ForeignFrom<payments::MandateType> for storage_enums::MandateDataType {
-    fn foreign_from(from: payments::MandateType) -> Self {
+impl ForeignFrom<storage_enums::CaptureStatus> for storage_enums::AttemptStatus {
+    fn foreign_from(capture_status: storage_enums::CaptureStatus) -> Self {
+        match capture_status {
+            storage_enums::CaptureStatus::Charged => Self::Charged,
+            storage_enums::CaptureStatus::Started | storage_enums::CaptureStatus::Pending => {
+                Self::CaptureInitiated
+            }
+            storage_enums::CaptureStatus::Failed => Self::CaptureFailed,
+        }
+    }
+}
+
+impl ForeignTryFrom<payments::MandateType> for storage_enums::MandateDataType {
+    type Error = error_stack::Report<common_utils::errors::ValidationError>;
+
+    fn foreign_try_from(from: payments::MandateType) -> Result<Self, Self::Error> {
         match from {
-            payments::MandateType::SingleUse(inner) => Self::SingleUse(inner.foreign_into()),
-            payments::MandateType::MultiUse(inner) => {
-                Self::MultiUse(inner.map(ForeignInto::foreign_into))
+            payments::MandateType::SingleUse(inner) => {
+                Ok(Self::SingleUse(inner.foreign_try_into()?))
             }
+            payments::MandateType::MultiUse(inner) => Ok(Self::MultiUse(
+                inner.map(ForeignTryInto::foreign_try_into).transpose()?,
+            )),
         }
     }
 }
@@ -272,11 +481,6 @@ impl ForeignTryFrom<api_enums::Connector> for common_enums::RoutableConnectors {
             api_enums::Connector::Globalpay => Self::Globalpay,
             api_enums::Connector::Globepay => Self::Globepay,
             api_enums::Connector::Gocardless => Self::Gocardless,
-            api_enums::Connector::Gpayments => {
-                Err(common_utils::errors::ValidationError::InvalidValue {
-                    message: "gpayments is not a routable connector".to_string(),
-                })?
-            }
             api_enums::Connector::Helcim => Self::Helcim,
             api_enums::Connector::Iatapay => Self::Iatapay,
             api_enums::Connector::Itaubank => Self::Itaubank,
@@ -284,11 +488,6 @@ impl ForeignTryFrom<api_enums::Connector> for common_enums::RoutableConnectors {
             api_enums::Connector::Mifinity => Self::Mifinity,
             api_enums::Connector::Mollie => Self::Mollie,
             api_enums::Connector::Multisafepay => Self::Multisafepay,
-            api_enums::Connector::Netcetera => {
-                Err(common_utils::errors::ValidationError::InvalidValue {
-                    message: "netcetera is not a routable connector".to_string(),
-                })?
-            }
             api_enums::Connector::Nexinets => Self::Nexinets,
             api_enums::Connector::Nexixpay => Self::Nexixpay,
             api_enums::Connector::Nmi => Self::Nmi,
@@ -308,31 +507,32 @@ impl ForeignTryFrom<api_enums::Connector> for common_enums::RoutableConnectors {
             api_enums::Connector::Rapyd => Self::Rapyd,
             api_enums::Connector::Razorpay => Self::Razorpay,
             api_enums::Connector::Shift4 => Self::Shift4,
-            api_enums::Connector::Signifyd => {
-                Err(common_utils::errors::ValidationError::InvalidValue {
-                    message: "signifyd is not a routable connector".to_string(),
-                })?
-            }
-            api_enums::Connector::Riskified => {
-                Err(common_utils::errors::ValidationError::InvalidValue {
-                    message: "riskified is not a routable connector".to_string(),
-                })?
-            }
             api_enums::Connector::Square => Self::Square,
             api_enums::Connector::Stax => Self::Stax,
             api_enums::Connector::Stripe => Self::Stripe,
-            // api_enums::Connector::Taxjar => Self::Taxjar,
-            // api_enums::Connector::Thunes => Self::Thunes,
             api_enums::Connector::Trustpay => Self::Trustpay,
             api_enums::Connector::Tsys => Self::Tsys,
             api_enums::Connector::Volt => Self::Volt,
             api_enums::Connector::Wellsfargo => Self::Wellsfargo,
-            // api_enums::Connector::Wellsfargopayout => Self::Wellsfargopayout,
             api_enums::Connector::Wise => Self::Wise,
             api_enums::Connector::Worldline => Self::Worldline,
             api_enums::Connector::Worldpay => Self::Worldpay,
             api_enums::Connector::Zen => Self::Zen,
             api_enums::Connector::Zsl => Self::Zsl,
+            non_routable_connector @ (api_enums::Connector::Gpayments
+            | api_enums::Connector::Netcetera
+            | api_enums::Connector::Signifyd
+            | api_enums::Connector::Riskified
+            | api_enums::Connector::Threedsecureio) => {
+                Err(common_utils::errors::ValidationError::InvalidValue {
+                    message: format!("{non_routable_connector} is not a routable connector"),
+                })?
+            }
+            api_enums::Connector::Taxjar => {
+                Err(common_utils::errors::ValidationError::InvalidValue {
+                    message: "Taxjar is not a routable connector".to_string(),
+                })?
+            }
             #[cfg(feature = "dummy_connector")]
             api_enums::Connector::DummyConnector1 => Self::DummyConnector1,
             #[cfg(feature = "dummy_connector")]
@@ -347,20 +547,64 @@ impl ForeignTryFrom<api_enums::Connector> for common_enums::RoutableConnectors {
             api_enums::Connector::DummyConnector6 => Self::DummyConnector6,
             #[cfg(feature = "dummy_connector")]
             api_enums::Connector::DummyConnector7 => Self::DummyConnector7,
-            api_enums::Connector::Threedsecureio => {
-                Err(common_utils::errors::ValidationError::InvalidValue {
-                    message: "threedsecureio is not a routable connector".to_string(),
-                })?
-            }
-            api_enums::Connector::Taxjar => {
-                Err(common_utils::errors::ValidationError::InvalidValue {
-                    message: "Taxjar is not a routable connector".to_string(),
-                })?
-            }
         })
     }
 }
 
+/// Classifies the algorithm returned by a connector's `get_webhook_source_verification_algorithm`
+impl ForeignFrom<&(dyn common_utils::crypto::VerifySignature + Send)>
+    for router_types::WebhookVerificationScheme
+{
+    fn foreign_from(algorithm: &(dyn common_utils::crypto::VerifySignature + Send)) -> Self {
+        match algorithm.algorithm_kind() {
+            common_utils::crypto::SignatureAlgorithmKind::KeyedHash => Self::Hmac,
+            common_utils::crypto::SignatureAlgorithmKind::Digest => Self::SignatureHeader,
+            common_utils::crypto::SignatureAlgorithmKind::NoAlgorithm => Self::Unverified,
+        }
+    }
+}
+
+/// Derives the currencies a connector supports from its `pm_filters` config. The connector is
+/// unrestricted if it has no filters, or if any of its filters does not restrict the currency.
+impl ForeignFrom<(api_enums::Connector, &settings::ConnectorFilters)>
+    for router_types::ConnectorCurrencySupport
+{
+    fn foreign_from(
+        (connector, pm_filters): (api_enums::Connector, &settings::ConnectorFilters),
+    ) -> Self {
+        pm_filters
+            .0
+            .get(connector.to_string().as_str())
+            .or_else(|| pm_filters.0.get("default"))
+            .and_then(|filters| {
+                filters.0.values().try_fold(
+                    std::collections::HashSet::new(),
+                    |mut currencies, filter| {
+                        currencies.extend(filter.currency.as_ref()?.iter().copied());
+                        Some(currencies)
+                    },
+                )
+            })
+            .filter(|currencies| !currencies.is_empty())
+            .map_or(Self::All, Self::Only)
+    }
+}
+
+impl ForeignTryFrom<&str> for api_enums::Connector {
+    type Error = error_stack::Report<common_utils::errors::ValidationError>;
+
+    fn foreign_try_from(connector_name: &str) -> Result<Self, Self::Error> {
+        use std::str::FromStr;
+
+        // Connector names are always serialized in lowercase
+        Self::from_str(&connector_name.to_lowercase()).change_context(
+            common_utils::errors::ValidationError::InvalidValue {
+                message: format!("{connector_name} is not a known connector"),
+            },
+        )
+    }
+}
+
 impl ForeignFrom<storage_enums::MandateAmountData> for payments::MandateAmountData {
     fn foreign_from(from: storage_enums::MandateAmountData) -> Self {
         Self {
@@ -428,14 +672,143 @@ impl ForeignFrom<payments::MandateData> for hyperswitch_domain_models::mandates:
     }
 }
 
-impl ForeignFrom<payments::MandateAmountData> for storage_enums::MandateAmountData {
-    fn foreign_from(from: payments::MandateAmountData) -> Self {
-        Self {
+/// Validates the mandate amount before converting the mandate data: its dates must be ordered,
+/// and its currency must match the currency of the payment (second), when that is known.
+impl ForeignTryFrom<(payments::MandateData, Option<common_enums::Currency>)>
+    for hyperswitch_domain_models::mandates::MandateData
+{
+    type Error = error_stack::Report<common_utils::errors::ValidationError>;
+
+    fn foreign_try_from(
+        (mandate_data, payment_currency): (payments::MandateData, Option<common_enums::Currency>),
+    ) -> Result<Self, Self::Error> {
+        let mandate_amount_data = match &mandate_data.mandate_type {
+            Some(payments::MandateType::SingleUse(mandate_amount_data))
+            | Some(payments::MandateType::MultiUse(Some(mandate_amount_data))) => {
+                Some(mandate_amount_data)
+            }
+            Some(payments::MandateType::MultiUse(None)) | None => None,
+        };
+        if let Some(mandate_amount_data) = mandate_amount_data {
+            validate_mandate_amount_dates(mandate_amount_data)?;
+        }
+        let mandate_currency =
+            mandate_amount_data.map(|mandate_amount_data| mandate_amount_data.currency);
+        if let Some((mandate_currency, payment_currency)) = mandate_currency.zip(payment_currency) {
+            when(mandate_currency != payment_currency, || {
+                Err(report!(
+                    common_utils::errors::ValidationError::InvalidValue {
+                        message: format!(
+                            "Mandate currency {} does not match the payment currency {}",
+                            mandate_currency, payment_currency
+                        ),
+                    }
+                ))
+            })?;
+        }
+
+        Ok(Self::foreign_from(mandate_data))
+    }
+}
+
+fn validate_mandate_amount_dates(
+    mandate_amount_data: &payments::MandateAmountData,
+) -> Result<(), error_stack::Report<common_utils::errors::ValidationError>> {
+    if let (Some(start_date), Some(end_date)) =
+        (mandate_amount_data.start_date, mandate_amount_data.end_date)
+    {
+        when(end_date < start_date, || {
+            Err(report!(
+                common_utils::errors::ValidationError::InvalidValue {
+                    message: "Mandate end_date cannot be earlier than start_date".to_string(),
+                }
+            ))
+        })?;
+    }
+    Ok(())
+}
+
+impl ForeignTryFrom<payments::MandateAmountData> for storage_enums::MandateAmountData {
+    type Error = error_stack::Report<common_utils::errors::ValidationError>;
+
+    fn foreign_try_from(from: payments::MandateAmountData) -> Result<Self, Self::Error> {
+        validate_mandate_amount_dates(&from)?;
+
+        Ok(Self {
             amount: from.amount,
             currency: from.currency,
             start_date: from.start_date,
             end_date: from.end_date,
             metadata: from.metadata,
+        })
+    }
+}
+
+/// Computes the amount of a mandate remaining after `consumed` has already been debited, which
+/// never goes below zero.
+impl
+    ForeignFrom<(
+        &storage_enums::MandateAmountData,
+        common_utils::types::MinorUnit,
+    )> for payments::MandateAmountRemaining
+{
+    fn foreign_from(
+        (mandate_amount_data, consumed): (
+            &storage_enums::MandateAmountData,
+            common_utils::types::MinorUnit,
+        ),
+    ) -> Self {
+        let remaining_amount = mandate_amount_data
+            .amount
+            .get_amount_as_i64()
+            .saturating_sub(consumed.get_amount_as_i64())
+            .max(0);
+        Self {
+            amount: common_utils::types::MinorUnit::new(remaining_amount),
+            currency: mandate_amount_data.currency,
+        }
+    }
+}
+
+impl ForeignFrom<crate::types::ConnectorResponseStatus> for storage_enums::AttemptStatus {
+    fn foreign_from(status: crate::types::ConnectorResponseStatus) -> Self {
+        match status {
+            crate::types::ConnectorResponseStatus::Approved => Self::Charged,
+            crate::types::ConnectorResponseStatus::Declined => Self::Failure,
+            crate::types::ConnectorResponseStatus::Pending => Self::Pending,
+            crate::types::ConnectorResponseStatus::Authorized => Self::Authorized,
+            crate::types::ConnectorResponseStatus::Voided => Self::Voided,
+        }
+    }
+}
+
+impl ForeignFrom<storage_enums::AttemptStatus> for router_types::RetryEligibility {
+    fn foreign_from(status: storage_enums::AttemptStatus) -> Self {
+        match status {
+            storage_enums::AttemptStatus::AuthenticationFailed
+            | storage_enums::AttemptStatus::AuthorizationFailed
+            | storage_enums::AttemptStatus::Failure => Self::Retryable,
+            storage_enums::AttemptStatus::RouterDeclined
+            | storage_enums::AttemptStatus::Authorized
+            | storage_enums::AttemptStatus::Charged
+            | storage_enums::AttemptStatus::Voided
+            | storage_enums::AttemptStatus::CaptureFailed
+            | storage_enums::AttemptStatus::VoidFailed
+            | storage_enums::AttemptStatus::AutoRefunded
+            | storage_enums::AttemptStatus::PartialCharged
+            | storage_enums::AttemptStatus::PartialChargedAndChargeable => Self::Terminal,
+            storage_enums::AttemptStatus::Started
+            | storage_enums::AttemptStatus::AuthenticationPending
+            | storage_enums::AttemptStatus::AuthenticationSuccessful
+            | storage_enums::AttemptStatus::Authorizing
+            | storage_enums::AttemptStatus::CodInitiated
+            | storage_enums::AttemptStatus::VoidInitiated
+            | storage_enums::AttemptStatus::CaptureInitiated
+            | storage_enums::AttemptStatus::Unresolved
+            | storage_enums::AttemptStatus::Pending
+            | storage_enums::AttemptStatus::PaymentMethodAwaited
+            | storage_enums::AttemptStatus::ConfirmationAwaited
+            | storage_enums::AttemptStatus::DeviceDataCollectionPending => Self::InProgress,
         }
     }
 }
@@ -512,7 +885,6 @@ impl ForeignFrom<api_enums::PaymentMethodType> for api_enums::PaymentMethod {
             | api_enums::PaymentMethodType::OnlineBankingPoland
             | api_enums::PaymentMethodType::OnlineBankingSlovakia
             | api_enums::PaymentMethodType::OpenBankingUk
-            | api_enums::PaymentMethodType::OpenBankingPIS
             | api_enums::PaymentMethodType::Przelewy24
             | api_enums::PaymentMethodType::Trustly
             | api_enums::PaymentMethodType::Bizum
@@ -566,10 +938,237 @@ impl ForeignFrom<api_enums::PaymentMethodType> for api_enums::PaymentMethod {
             | api_enums::PaymentMethodType::DuitNow
             | api_enums::PaymentMethodType::PromptPay
             | api_enums::PaymentMethodType::VietQr => Self::RealTimePayment,
+            api_enums::PaymentMethodType::OpenBankingPIS => Self::OpenBanking,
         }
     }
 }
 
+/// Countries which are part of the Single Euro Payments Area
+const SEPA_COUNTRIES: &[common_enums::CountryAlpha2] = &[
+    common_enums::CountryAlpha2::AD,
+    common_enums::CountryAlpha2::AT,
+    common_enums::CountryAlpha2::BE,
+    common_enums::CountryAlpha2::BG,
+    common_enums::CountryAlpha2::CH,
+    common_enums::CountryAlpha2::CY,
+    common_enums::CountryAlpha2::CZ,
+    common_enums::CountryAlpha2::DE,
+    common_enums::CountryAlpha2::DK,
+    common_enums::CountryAlpha2::EE,
+    common_enums::CountryAlpha2::ES,
+    common_enums::CountryAlpha2::FI,
+    common_enums::CountryAlpha2::FR,
+    common_enums::CountryAlpha2::GB,
+    common_enums::CountryAlpha2::GR,
+    common_enums::CountryAlpha2::HR,
+    common_enums::CountryAlpha2::HU,
+    common_enums::CountryAlpha2::IE,
+    common_enums::CountryAlpha2::IS,
+    common_enums::CountryAlpha2::IT,
+    common_enums::CountryAlpha2::LI,
+    common_enums::CountryAlpha2::LT,
+    common_enums::CountryAlpha2::LU,
+    common_enums::CountryAlpha2::LV,
+    common_enums::CountryAlpha2::MC,
+    common_enums::CountryAlpha2::MT,
+    common_enums::CountryAlpha2::NL,
+    common_enums::CountryAlpha2::NO,
+    common_enums::CountryAlpha2::PL,
+    common_enums::CountryAlpha2::PT,
+    common_enums::CountryAlpha2::RO,
+    common_enums::CountryAlpha2::SE,
+    common_enums::CountryAlpha2::SI,
+    common_enums::CountryAlpha2::SK,
+    common_enums::CountryAlpha2::SM,
+    common_enums::CountryAlpha2::VA,
+];
+
+/// Lists the countries a payment method type is generally available in. The list is empty for
+/// payment method types which are not tied to specific countries, such as cards and global wallets.
+impl ForeignFrom<api_enums::PaymentMethodType> for Vec<common_enums::CountryAlpha2> {
+    fn foreign_from(payment_method_type: api_enums::PaymentMethodType) -> Self {
+        let countries: &[common_enums::CountryAlpha2] =
+            match payment_method_type {
+                // Not tied to any specific country
+                api_enums::PaymentMethodType::Credit
+                | api_enums::PaymentMethodType::Debit
+                | api_enums::PaymentMethodType::ApplePay
+                | api_enums::PaymentMethodType::GooglePay
+                | api_enums::PaymentMethodType::SamsungPay
+                | api_enums::PaymentMethodType::Paypal
+                | api_enums::PaymentMethodType::CardRedirect
+                | api_enums::PaymentMethodType::ClassicReward
+                | api_enums::PaymentMethodType::Evoucher
+                | api_enums::PaymentMethodType::CryptoCurrency
+                | api_enums::PaymentMethodType::Givex
+                | api_enums::PaymentMethodType::PaySafeCard
+                | api_enums::PaymentMethodType::Mifinity
+                | api_enums::PaymentMethodType::LocalBankRedirect
+                | api_enums::PaymentMethodType::LocalBankTransfer
+                | api_enums::PaymentMethodType::OpenBankingPIS => &[],
+                api_enums::PaymentMethodType::Ach
+                | api_enums::PaymentMethodType::Cashapp
+                | api_enums::PaymentMethodType::Paze
+                | api_enums::PaymentMethodType::Venmo => &[common_enums::CountryAlpha2::US],
+                api_enums::PaymentMethodType::Affirm => &[
+                    common_enums::CountryAlpha2::US,
+                    common_enums::CountryAlpha2::CA,
+                ],
+                api_enums::PaymentMethodType::AfterpayClearpay => &[
+                    common_enums::CountryAlpha2::AU,
+                    common_enums::CountryAlpha2::CA,
+                    common_enums::CountryAlpha2::GB,
+                    common_enums::CountryAlpha2::NZ,
+                    common_enums::CountryAlpha2::US,
+                ],
+                api_enums::PaymentMethodType::Alfamart
+                | api_enums::PaymentMethodType::BcaBankTransfer
+                | api_enums::PaymentMethodType::BniVa
+                | api_enums::PaymentMethodType::BriVa
+                | api_enums::PaymentMethodType::CimbVa
+                | api_enums::PaymentMethodType::Dana
+                | api_enums::PaymentMethodType::DanamonVa
+                | api_enums::PaymentMethodType::GoPay
+                | api_enums::PaymentMethodType::Indomaret
+                | api_enums::PaymentMethodType::MandiriVa
+                | api_enums::PaymentMethodType::PermataBankTransfer => {
+                    &[common_enums::CountryAlpha2::ID]
+                }
+                api_enums::PaymentMethodType::AliPay | api_enums::PaymentMethodType::WeChatPay => {
+                    &[common_enums::CountryAlpha2::CN]
+                }
+                api_enums::PaymentMethodType::AliPayHk | api_enums::PaymentMethodType::Fps => {
+                    &[common_enums::CountryAlpha2::HK]
+                }
+                api_enums::PaymentMethodType::Alma => &[common_enums::CountryAlpha2::FR],
+                api_enums::PaymentMethodType::Atome => &[
+                    common_enums::CountryAlpha2::MY,
+                    common_enums::CountryAlpha2::SG,
+                ],
+                api_enums::PaymentMethodType::Bacs
+                | api_enums::PaymentMethodType::OpenBankingUk => &[common_enums::CountryAlpha2::GB],
+                api_enums::PaymentMethodType::BancontactCard => &[common_enums::CountryAlpha2::BE],
+                api_enums::PaymentMethodType::Becs => &[common_enums::CountryAlpha2::AU],
+                api_enums::PaymentMethodType::Benefit => &[common_enums::CountryAlpha2::BH],
+                api_enums::PaymentMethodType::Bizum => &[common_enums::CountryAlpha2::ES],
+                api_enums::PaymentMethodType::Blik
+                | api_enums::PaymentMethodType::OnlineBankingPoland
+                | api_enums::PaymentMethodType::Przelewy24 => &[common_enums::CountryAlpha2::PL],
+                api_enums::PaymentMethodType::Boleto | api_enums::PaymentMethodType::Pix => {
+                    &[common_enums::CountryAlpha2::BR]
+                }
+                api_enums::PaymentMethodType::DuitNow
+                | api_enums::PaymentMethodType::OnlineBankingFpx
+                | api_enums::PaymentMethodType::TouchNGo => &[common_enums::CountryAlpha2::MY],
+                api_enums::PaymentMethodType::Efecty | api_enums::PaymentMethodType::Pse => {
+                    &[common_enums::CountryAlpha2::CO]
+                }
+                api_enums::PaymentMethodType::Eps => &[common_enums::CountryAlpha2::AT],
+                api_enums::PaymentMethodType::Gcash => &[common_enums::CountryAlpha2::PH],
+                api_enums::PaymentMethodType::Giropay => &[common_enums::CountryAlpha2::DE],
+                api_enums::PaymentMethodType::Ideal => &[common_enums::CountryAlpha2::NL],
+                api_enums::PaymentMethodType::Interac | api_enums::PaymentMethodType::PayBright => {
+                    &[common_enums::CountryAlpha2::CA]
+                }
+                api_enums::PaymentMethodType::KakaoPay => &[common_enums::CountryAlpha2::KR],
+                api_enums::PaymentMethodType::Klarna => &[
+                    common_enums::CountryAlpha2::AT,
+                    common_enums::CountryAlpha2::AU,
+                    common_enums::CountryAlpha2::BE,
+                    common_enums::CountryAlpha2::CA,
+                    common_enums::CountryAlpha2::CH,
+                    common_enums::CountryAlpha2::CZ,
+                    common_enums::CountryAlpha2::DE,
+                    common_enums::CountryAlpha2::DK,
+                    common_enums::CountryAlpha2::ES,
+                    common_enums::CountryAlpha2::FI,
+                    common_enums::CountryAlpha2::FR,
+                    common_enums::CountryAlpha2::GB,
+                    common_enums::CountryAlpha2::GR,
+                    common_enums::CountryAlpha2::IE,
+                    common_enums::CountryAlpha2::IT,
+                    common_enums::CountryAlpha2::NL,
+                    common_enums::CountryAlpha2::NO,
+                    common_enums::CountryAlpha2::NZ,
+                    common_enums::CountryAlpha2::PL,
+                    common_enums::CountryAlpha2::PT,
+                    common_enums::CountryAlpha2::SE,
+                    common_enums::CountryAlpha2::US,
+                ],
+                api_enums::PaymentMethodType::Knet => &[common_enums::CountryAlpha2::KW],
+                api_enums::PaymentMethodType::MbWay | api_enums::PaymentMethodType::Multibanco => {
+                    &[common_enums::CountryAlpha2::PT]
+                }
+                api_enums::PaymentMethodType::MobilePay => &[
+                    common_enums::CountryAlpha2::DK,
+                    common_enums::CountryAlpha2::FI,
+                ],
+                api_enums::PaymentMethodType::Momo
+                | api_enums::PaymentMethodType::MomoAtm
+                | api_enums::PaymentMethodType::VietQr => &[common_enums::CountryAlpha2::VN],
+                api_enums::PaymentMethodType::OnlineBankingCzechRepublic => {
+                    &[common_enums::CountryAlpha2::CZ]
+                }
+                api_enums::PaymentMethodType::OnlineBankingFinland => {
+                    &[common_enums::CountryAlpha2::FI]
+                }
+                api_enums::PaymentMethodType::OnlineBankingSlovakia => {
+                    &[common_enums::CountryAlpha2::SK]
+                }
+                api_enums::PaymentMethodType::OnlineBankingThailand
+                | api_enums::PaymentMethodType::PromptPay => &[common_enums::CountryAlpha2::TH],
+                api_enums::PaymentMethodType::Oxxo => &[common_enums::CountryAlpha2::MX],
+                api_enums::PaymentMethodType::PagoEfectivo => &[common_enums::CountryAlpha2::PE],
+                api_enums::PaymentMethodType::RedCompra => &[common_enums::CountryAlpha2::CL],
+                api_enums::PaymentMethodType::RedPagos => &[common_enums::CountryAlpha2::UY],
+                api_enums::PaymentMethodType::Sepa => SEPA_COUNTRIES,
+                api_enums::PaymentMethodType::Sofort => &[
+                    common_enums::CountryAlpha2::AT,
+                    common_enums::CountryAlpha2::BE,
+                    common_enums::CountryAlpha2::CH,
+                    common_enums::CountryAlpha2::DE,
+                    common_enums::CountryAlpha2::ES,
+                    common_enums::CountryAlpha2::IT,
+                    common_enums::CountryAlpha2::NL,
+                    common_enums::CountryAlpha2::PL,
+                ],
+                api_enums::PaymentMethodType::Swish => &[common_enums::CountryAlpha2::SE],
+                api_enums::PaymentMethodType::Trustly => &[
+                    common_enums::CountryAlpha2::AT,
+                    common_enums::CountryAlpha2::DE,
+                    common_enums::CountryAlpha2::DK,
+                    common_enums::CountryAlpha2::EE,
+                    common_enums::CountryAlpha2::ES,
+                    common_enums::CountryAlpha2::FI,
+                    common_enums::CountryAlpha2::GB,
+                    common_enums::CountryAlpha2::LT,
+                    common_enums::CountryAlpha2::LV,
+                    common_enums::CountryAlpha2::NL,
+                    common_enums::CountryAlpha2::NO,
+                    common_enums::CountryAlpha2::PL,
+                    common_enums::CountryAlpha2::SE,
+                ],
+                api_enums::PaymentMethodType::Twint => &[common_enums::CountryAlpha2::CH],
+                api_enums::PaymentMethodType::UpiCollect
+                | api_enums::PaymentMethodType::UpiIntent => &[common_enums::CountryAlpha2::IN],
+                api_enums::PaymentMethodType::Vipps => &[common_enums::CountryAlpha2::NO],
+                api_enums::PaymentMethodType::Walley => &[
+                    common_enums::CountryAlpha2::DK,
+                    common_enums::CountryAlpha2::FI,
+                    common_enums::CountryAlpha2::NO,
+                    common_enums::CountryAlpha2::SE,
+                ],
+                api_enums::PaymentMethodType::SevenEleven
+                | api_enums::PaymentMethodType::Lawson
+                | api_enums::PaymentMethodType::MiniStop
+                | api_enums::PaymentMethodType::FamilyMart
+                | api_enums::PaymentMethodType::Seicomart
+                | api_enums::PaymentMethodType::PayEasy => &[common_enums::CountryAlpha2::JP],
+            };
+        countries.to_vec()
+    }
+}
+
 impl ForeignTryFrom<payments::PaymentMethodData> for api_enums::PaymentMethod {
     type Error = errors::ApiErrorResponse;
     fn foreign_try_from(
@@ -601,14 +1200,140 @@ impl ForeignTryFrom<payments::PaymentMethodData> for api_enums::PaymentMethod {
     }
 }
 
+impl ForeignTryFrom<&payments::PaymentMethodData> for crate::types::FingerprintInput {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn foreign_try_from(
+        payment_method_data: &payments::PaymentMethodData,
+    ) -> Result<Self, Self::Error> {
+        match payment_method_data {
+            payments::PaymentMethodData::Card(card) => Ok(Self::Card(card.card_number.clone())),
+            payments::PaymentMethodData::BankDebit(bank_debit_data) => match bank_debit_data {
+                payments::BankDebitData::SepaBankDebit { iban, .. } => Ok(Self::Iban(iban.clone())),
+                payments::BankDebitData::AchBankDebit {
+                    account_number,
+                    routing_number: bank_code,
+                    ..
+                }
+                | payments::BankDebitData::BecsBankDebit {
+                    account_number,
+                    bsb_number: bank_code,
+                    ..
+                }
+                | payments::BankDebitData::BacsBankDebit {
+                    account_number,
+                    sort_code: bank_code,
+                    ..
+                } => Ok(Self::BankAccount {
+                    account_number: account_number.clone(),
+                    bank_code: bank_code.clone(),
+                }),
+            },
+            payments::PaymentMethodData::MandatePayment
+            | payments::PaymentMethodData::Reward
+            | payments::PaymentMethodData::CardRedirect(..)
+            | payments::PaymentMethodData::Wallet(..)
+            | payments::PaymentMethodData::PayLater(..)
+            | payments::PaymentMethodData::BankRedirect(..)
+            | payments::PaymentMethodData::BankTransfer(..)
+            | payments::PaymentMethodData::RealTimePayment(..)
+            | payments::PaymentMethodData::Crypto(..)
+            | payments::PaymentMethodData::Upi(..)
+            | payments::PaymentMethodData::Voucher(..)
+            | payments::PaymentMethodData::GiftCard(..)
+            | payments::PaymentMethodData::CardToken(..)
+            | payments::PaymentMethodData::OpenBanking(..) => {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Payment method data has no stable identifier to fingerprint"
+                        .to_string(),
+                }))
+            }
+        }
+    }
+}
+
 impl ForeignFrom<storage_enums::RefundStatus> for Option<storage_enums::EventType> {
     fn foreign_from(value: storage_enums::RefundStatus) -> Self {
         match value {
             storage_enums::RefundStatus::Success => Some(storage_enums::EventType::RefundSucceeded),
             storage_enums::RefundStatus::Failure => Some(storage_enums::EventType::RefundFailed),
-            api_enums::RefundStatus::ManualReview
-            | api_enums::RefundStatus::Pending
-            | api_enums::RefundStatus::TransactionFailure => None,
+            storage_enums::RefundStatus::Pending => {
+                Some(storage_enums::EventType::RefundProcessing)
+            }
+            storage_enums::RefundStatus::ManualReview
+            | storage_enums::RefundStatus::TransactionFailure => None,
+        }
+    }
+}
+
+impl
+    ForeignFrom<(
+        storage_enums::RefundStatus,
+        common_utils::types::MinorUnit,
+        common_utils::types::MinorUnit,
+    )> for Option<api_models::refunds::RefundEventDetails>
+{
+    fn foreign_from(
+        (refund_status, refund_amount, payment_amount): (
+            storage_enums::RefundStatus,
+            common_utils::types::MinorUnit,
+            common_utils::types::MinorUnit,
+        ),
+    ) -> Self {
+        Option::<storage_enums::EventType>::foreign_from(refund_status).map(|event_type| {
+            api_models::refunds::RefundEventDetails {
+                event_type,
+                is_partial_refund: refund_amount < payment_amount,
+            }
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl
+    ForeignTryFrom<(
+        api_models::refunds::RefundRequest,
+        common_utils::types::MinorUnit,
+        common_utils::types::MinorUnit,
+    )> for router_types::ValidatedRefund
+{
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn foreign_try_from(
+        (request, payment_amount, already_refunded_amount): (
+            api_models::refunds::RefundRequest,
+            common_utils::types::MinorUnit,
+            common_utils::types::MinorUnit,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let amount = request.amount.unwrap_or(payment_amount);
+
+        crate::core::refunds::validator::validate_refundable_amount(
+            payment_amount.get_amount_as_i64(),
+            already_refunded_amount.get_amount_as_i64(),
+            amount.get_amount_as_i64(),
+        )
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "refund amount {} exceeds the refundable amount {} of the payment",
+                amount,
+                payment_amount - already_refunded_amount
+            ),
+        })?;
+
+        Ok(Self { request, amount })
+    }
+}
+
+impl ForeignFrom<&storage::Refund> for router_types::RefundLedgerRow {
+    fn foreign_from(refund: &storage::Refund) -> Self {
+        Self {
+            refund_id: refund.refund_id.clone(),
+            payment_id: refund.payment_id.clone(),
+            amount: refund.refund_amount,
+            currency: refund.currency,
+            connector: refund.connector.clone(),
+            status: refund.refund_status.to_string(),
+            created_at: refund.created_at,
         }
     }
 }
@@ -645,6 +1370,7 @@ impl ForeignFrom<storage_enums::DisputeStatus> for storage_enums::EventType {
             storage_enums::DisputeStatus::DisputeAccepted => Self::DisputeAccepted,
             storage_enums::DisputeStatus::DisputeCancelled => Self::DisputeCancelled,
             storage_enums::DisputeStatus::DisputeChallenged => Self::DisputeChallenged,
+            storage_enums::DisputeStatus::DisputeUnderReview => Self::DisputeUnderReview,
             storage_enums::DisputeStatus::DisputeWon => Self::DisputeWon,
             storage_enums::DisputeStatus::DisputeLost => Self::DisputeLost,
         }
@@ -661,6 +1387,41 @@ impl ForeignFrom<storage_enums::MandateStatus> for Option<storage_enums::EventTy
     }
 }
 
+/// Builds the outgoing webhook event for the current status of a mandate, along with its payload.
+/// Card details are taken from the stored payment method data, as the locker is not reachable here.
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+impl ForeignFrom<(&storage::Mandate, &domain::PaymentMethod)>
+    for Option<(
+        storage_enums::EventType,
+        api_models::mandates::MandateResponse,
+    )>
+{
+    fn foreign_from(
+        (mandate, payment_method): (&storage::Mandate, &domain::PaymentMethod),
+    ) -> Self {
+        let event_type = Option::<storage_enums::EventType>::foreign_from(mandate.mandate_status)?;
+
+        let card = (payment_method.payment_method == Some(storage_enums::PaymentMethod::Card))
+            .then(|| {
+                get_card_details_from_payment_method_data(
+                    payment_method
+                        .payment_method_data
+                        .clone()
+                        .map(|data| data.into_inner().expose()),
+                )
+            })
+            .flatten()
+            .map(|card| api_types::mandates::MandateCardDetails::from(card).into_inner());
+        let mandate_response =
+            api_types::mandates::build_mandate_response(mandate, payment_method, card);
+
+        Some((event_type, mandate_response))
+    }
+}
+
 impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enums::RefundStatus {
     type Error = errors::ValidationError;
 
@@ -715,6 +1476,87 @@ impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enum
     }
 }
 
+impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enums::IntentStatus {
+    type Error = errors::ValidationError;
+
+    fn foreign_try_from(
+        value: api_models::webhooks::IncomingWebhookEvent,
+    ) -> Result<Self, Self::Error> {
+        match value {
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentSuccess => Ok(Self::Succeeded),
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentFailure
+            | api_models::webhooks::IncomingWebhookEvent::PaymentIntentAuthorizationFailure => {
+                Ok(Self::Failed)
+            }
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentProcessing => {
+                Ok(Self::Processing)
+            }
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentAuthorizationSuccess => {
+                Ok(Self::RequiresCapture)
+            }
+            api_models::webhooks::IncomingWebhookEvent::PaymentActionRequired => {
+                Ok(Self::RequiresCustomerAction)
+            }
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentCancelled => {
+                Ok(Self::Cancelled)
+            }
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "incoming_webhook_event_type",
+            }),
+        }
+    }
+}
+
+/// Resolves the class of the object an incoming webhook event refers to, along with the event
+/// type it maps to, by reusing the per-class status conversions of the event.
+impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent>
+    for (storage_enums::EventClass, storage_enums::EventType)
+{
+    type Error = errors::ValidationError;
+
+    fn foreign_try_from(
+        value: api_models::webhooks::IncomingWebhookEvent,
+    ) -> Result<Self, Self::Error> {
+        let event_class_and_type = storage_enums::IntentStatus::foreign_try_from(value)
+            .ok()
+            .and_then(Option::<storage_enums::EventType>::foreign_from)
+            .map(|event_type| (storage_enums::EventClass::Payments, event_type))
+            .or_else(|| {
+                storage_enums::RefundStatus::foreign_try_from(value)
+                    .ok()
+                    .and_then(Option::<storage_enums::EventType>::foreign_from)
+                    .map(|event_type| (storage_enums::EventClass::Refunds, event_type))
+            })
+            .or_else(|| {
+                storage_enums::DisputeStatus::foreign_try_from(value)
+                    .ok()
+                    .map(|dispute_status| {
+                        (
+                            storage_enums::EventClass::Disputes,
+                            storage_enums::EventType::foreign_from(dispute_status),
+                        )
+                    })
+            })
+            .or_else(|| {
+                storage_enums::MandateStatus::foreign_try_from(value)
+                    .ok()
+                    .and_then(Option::<storage_enums::EventType>::foreign_from)
+                    .map(|event_type| (storage_enums::EventClass::Mandates, event_type))
+            });
+        #[cfg(feature = "payouts")]
+        let event_class_and_type = event_class_and_type.or_else(|| {
+            storage_enums::PayoutStatus::foreign_try_from(value)
+                .ok()
+                .and_then(Option::<storage_enums::EventType>::foreign_from)
+                .map(|event_type| (storage_enums::EventClass::Payouts, event_type))
+        });
+
+        event_class_and_type.ok_or(errors::ValidationError::IncorrectValueProvided {
+            field_name: "incoming_webhook_event",
+        })
+    }
+}
+
 impl ForeignFrom<storage::Config> for api_types::Config {
     fn foreign_from(config: storage::Config) -> Self {
         Self {
@@ -778,6 +1620,105 @@ impl<'a> From<&'a domain::Address> for api_types::Address {
     }
 }
 
+impl domain::Address {
+    /// Converts the address to its api representation, retaining an empty `AddressDetails`
+    /// object instead of `None` when none of the address fields are populated.
+    ///
+    /// This is required by connectors which differentiate between an address that was not
+    /// provided and one that was provided without any details.
+    pub fn to_api_address_preserving_empty(&self) -> api_types::Address {
+        let api_address = api_types::Address::from(self);
+        api_types::Address {
+            address: Some(api_address.address.unwrap_or_default()),
+            ..api_address
+        }
+    }
+}
+
+/// Normalizes the phone number of the address to E.164, returning the country code as `+<digits>`
+/// and the subscriber number as digits only. Unlike the lenient `From<&domain::Address>`
+/// conversion, this rejects phone numbers which cannot be represented in E.164.
+impl ForeignTryFrom<&domain::Address> for api_types::PhoneDetails {
+    type Error = error_stack::Report<common_utils::errors::ValidationError>;
+
+    fn foreign_try_from(address: &domain::Address) -> Result<Self, Self::Error> {
+        const E164_MAX_DIGITS: usize = 15;
+
+        let country_code = address
+            .country_code
+            .as_deref()
+            .map(|country_code| country_code.trim().trim_start_matches('+'))
+            .filter(|country_code| !country_code.is_empty())
+            .ok_or(
+                common_utils::errors::ValidationError::MissingRequiredField {
+                    field_name: "country_code".to_string(),
+                },
+            )?;
+        let number = address
+            .phone_number
+            .as_ref()
+            .map(|phone_number| {
+                phone_number
+                    .get_inner()
+                    .peek()
+                    .chars()
+                    .filter(|character| !matches!(character, ' ' | '-' | '.' | '(' | ')'))
+                    .collect::<String>()
+            })
+            .filter(|number| !number.is_empty())
+            .ok_or(
+                common_utils::errors::ValidationError::MissingRequiredField {
+                    field_name: "phone_number".to_string(),
+                },
+            )?;
+
+        when(
+            !country_code
+                .chars()
+                .all(|character| character.is_ascii_digit())
+                || country_code.len() > 3
+                || country_code.starts_with('0'),
+            || {
+                Err(common_utils::errors::ValidationError::InvalidValue {
+                    message: "country_code must be 1 to 3 digits not starting with 0".to_string(),
+                })
+            },
+        )?;
+        when(
+            !number.chars().all(|character| character.is_ascii_digit())
+                || country_code.len() + number.len() > E164_MAX_DIGITS,
+            || {
+                Err(common_utils::errors::ValidationError::InvalidValue {
+                    message: "phone_number is not a valid E.164 phone number".to_string(),
+                })
+            },
+        )?;
+        // The parser error contains the phone number itself, so it is not propagated
+        common_utils::validation::validate_phone_number(&format!("+{country_code}{number}"))
+            .map_err(|_| common_utils::errors::ValidationError::InvalidValue {
+                message: "phone_number is not a valid E.164 phone number".to_string(),
+            })?;
+
+        Ok(Self {
+            number: Some(Secret::new(number)),
+            country_code: Some(format!("+{country_code}")),
+        })
+    }
+}
+
+/// Converts the first address if present, else falls back to the second one.
+/// Useful for falling back to the billing address when no shipping address is provided.
+impl ForeignFrom<(Option<&domain::Address>, Option<&domain::Address>)> for api_types::Address {
+    fn foreign_from(
+        (primary_address, fallback_address): (Option<&domain::Address>, Option<&domain::Address>),
+    ) -> Self {
+        primary_address
+            .or(fallback_address)
+            .map(Self::from)
+            .unwrap_or_default()
+    }
+}
+
 impl ForeignFrom<domain::Address> for api_types::Address {
     fn foreign_from(address: domain::Address) -> Self {
         // If all the fields of address are none, then pass the address as None
@@ -853,6 +1794,10 @@ impl
 
 impl ForeignFrom<diesel_models::api_keys::ApiKey> for api_models::api_keys::RetrieveApiKeyResponse {
     fn foreign_from(api_key: diesel_models::api_keys::ApiKey) -> Self {
+        let is_expired = api_key
+            .expires_at
+            .is_some_and(|expires_at| expires_at <= common_utils::date_time::now());
+
         Self {
             key_id: api_key.key_id,
             merchant_id: api_key.merchant_id,
@@ -861,6 +1806,22 @@ impl ForeignFrom<diesel_models::api_keys::ApiKey> for api_models::api_keys::Retr
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            is_expired,
+        }
+    }
+}
+
+impl ForeignFrom<(&diesel_models::api_keys::ApiKey, router_types::AuditAction)>
+    for router_types::AuditLogEntry
+{
+    fn foreign_from(
+        (api_key, action): (&diesel_models::api_keys::ApiKey, router_types::AuditAction),
+    ) -> Self {
+        Self {
+            key_id: api_key.key_id.clone(),
+            merchant_id: api_key.merchant_id.clone(),
+            action,
+            timestamp: common_utils::date_time::now(),
         }
     }
 }
@@ -896,6 +1857,9 @@ impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enum
             api_models::webhooks::IncomingWebhookEvent::DisputeChallenged => {
                 Ok(Self::DisputeChallenged)
             }
+            api_models::webhooks::IncomingWebhookEvent::DisputeUnderReview => {
+                Ok(Self::DisputeUnderReview)
+            }
             api_models::webhooks::IncomingWebhookEvent::DisputeWon => Ok(Self::DisputeWon),
             api_models::webhooks::IncomingWebhookEvent::DisputeLost => Ok(Self::DisputeLost),
             _ => Err(errors::ValidationError::IncorrectValueProvided {
@@ -905,19 +1869,99 @@ impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enum
     }
 }
 
+/// Computes the urgency of a dispute from the deadline to submit its evidence, relative to now
+impl ForeignFrom<Option<time::PrimitiveDateTime>> for api_models::disputes::DisputeUrgency {
+    fn foreign_from(challenge_required_by: Option<time::PrimitiveDateTime>) -> Self {
+        const DUE_SOON_THRESHOLD: time::Duration = time::Duration::hours(48);
+
+        let Some(challenge_required_by) = challenge_required_by else {
+            return Self::NoDeadline;
+        };
+        let time_remaining = challenge_required_by - common_utils::date_time::now();
+        if time_remaining.is_negative() {
+            Self::Overdue
+        } else if time_remaining <= DUE_SOON_THRESHOLD {
+            Self::DueSoon
+        } else {
+            Self::Ample
+        }
+    }
+}
+
+/// Reason codes of the card networks (Visa, Mastercard) and reasons of the connectors, mapped to
+/// the category of the dispute
+const DISPUTE_REASON_CODE_CATEGORIES: &[(&str, api_enums::DisputeCategory)] = &[
+    ("10.1", api_enums::DisputeCategory::Fraud),
+    ("10.2", api_enums::DisputeCategory::Fraud),
+    ("10.3", api_enums::DisputeCategory::Fraud),
+    ("10.4", api_enums::DisputeCategory::Fraud),
+    ("10.5", api_enums::DisputeCategory::Fraud),
+    ("4837", api_enums::DisputeCategory::Fraud),
+    ("4840", api_enums::DisputeCategory::Fraud),
+    ("4849", api_enums::DisputeCategory::Fraud),
+    ("4863", api_enums::DisputeCategory::Fraud),
+    ("4870", api_enums::DisputeCategory::Fraud),
+    ("4871", api_enums::DisputeCategory::Fraud),
+    ("fraudulent", api_enums::DisputeCategory::Fraud),
+    ("13.1", api_enums::DisputeCategory::ProductNotReceived),
+    ("4855", api_enums::DisputeCategory::ProductNotReceived),
+    (
+        "product_not_received",
+        api_enums::DisputeCategory::ProductNotReceived,
+    ),
+    ("13.3", api_enums::DisputeCategory::ProductUnacceptable),
+    ("4853", api_enums::DisputeCategory::ProductUnacceptable),
+    (
+        "product_unacceptable",
+        api_enums::DisputeCategory::ProductUnacceptable,
+    ),
+    ("12.6.1", api_enums::DisputeCategory::Duplicate),
+    ("4834", api_enums::DisputeCategory::Duplicate),
+    ("duplicate", api_enums::DisputeCategory::Duplicate),
+    ("13.6", api_enums::DisputeCategory::CreditNotProcessed),
+    ("4860", api_enums::DisputeCategory::CreditNotProcessed),
+    (
+        "credit_not_processed",
+        api_enums::DisputeCategory::CreditNotProcessed,
+    ),
+    ("13.2", api_enums::DisputeCategory::SubscriptionCanceled),
+    ("4841", api_enums::DisputeCategory::SubscriptionCanceled),
+    (
+        "subscription_canceled",
+        api_enums::DisputeCategory::SubscriptionCanceled,
+    ),
+];
+
+fn get_dispute_category(connector_reason_code: &str) -> Option<api_enums::DisputeCategory> {
+    let connector_reason_code = connector_reason_code.trim();
+    DISPUTE_REASON_CODE_CATEGORIES
+        .iter()
+        .find(|(reason_code, _)| reason_code.eq_ignore_ascii_case(connector_reason_code))
+        .map(|(_, dispute_category)| *dispute_category)
+}
+
 impl ForeignFrom<storage::Dispute> for api_models::disputes::DisputeResponse {
     fn foreign_from(dispute: storage::Dispute) -> Self {
+        // The evidence column defaults to an empty object when no evidence has been attached
+        let evidence = Some(dispute.evidence.expose()).filter(|evidence| {
+            !(evidence.is_null() || evidence.as_object().is_some_and(|map| map.is_empty()))
+        });
+        let dispute_category = dispute
+            .connector_reason_code
+            .as_deref()
+            .and_then(get_dispute_category);
         Self {
             dispute_id: dispute.dispute_id,
             payment_id: dispute.payment_id,
             attempt_id: dispute.attempt_id,
-            amount: dispute.amount,
+            amount: common_utils::types::MinorUnit::new(dispute.dispute_amount),
             currency: dispute.currency,
             dispute_stage: dispute.dispute_stage,
             dispute_status: dispute.dispute_status,
             connector: dispute.connector,
             connector_status: dispute.connector_status,
             connector_dispute_id: dispute.connector_dispute_id,
+            connector_transaction_id: None,
             connector_reason: dispute.connector_reason,
             connector_reason_code: dispute.connector_reason_code,
             challenge_required_by: dispute.challenge_required_by,
@@ -926,10 +1970,51 @@ impl ForeignFrom<storage::Dispute> for api_models::disputes::DisputeResponse {
             created_at: dispute.created_at,
             profile_id: dispute.profile_id,
             merchant_connector_id: dispute.merchant_connector_id,
+            evidence,
+            urgency: api_models::disputes::DisputeUrgency::foreign_from(
+                dispute.challenge_required_by,
+            ),
+            dispute_category,
         }
     }
 }
 
+#[cfg(feature = "v1")]
+impl ForeignFrom<(storage::Dispute, Option<&storage::PaymentAttempt>)>
+    for api_models::disputes::DisputeResponse
+{
+    fn foreign_from(
+        (dispute, payment_attempt): (storage::Dispute, Option<&storage::PaymentAttempt>),
+    ) -> Self {
+        let connector_transaction_id = payment_attempt
+            .filter(|payment_attempt| payment_attempt.attempt_id == dispute.attempt_id)
+            .and_then(|payment_attempt| payment_attempt.get_connector_payment_id())
+            .map(ToString::to_string);
+        Self {
+            connector_transaction_id,
+            ..Self::foreign_from(dispute)
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ForeignFrom<(storage::Dispute, Option<&storage::PaymentAttempt>)>
+    for (
+        storage_enums::EventType,
+        api_models::disputes::DisputeResponse,
+    )
+{
+    fn foreign_from(
+        (dispute, payment_attempt): (storage::Dispute, Option<&storage::PaymentAttempt>),
+    ) -> Self {
+        let event_type = storage_enums::EventType::foreign_from(dispute.dispute_status);
+        (
+            event_type,
+            api_models::disputes::DisputeResponse::foreign_from((dispute, payment_attempt)),
+        )
+    }
+}
+
 impl ForeignFrom<storage::Authorization> for payments::IncrementalAuthorizationResponse {
     fn foreign_from(authorization: storage::Authorization) -> Self {
         Self {
@@ -943,20 +2028,97 @@ impl ForeignFrom<storage::Authorization> for payments::IncrementalAuthorizationR
     }
 }
 
+/// Groups the connector error of an authorization. Returns `None` for successful
+/// authorizations and for authorizations without any error details.
+impl ForeignFrom<&storage::Authorization> for Option<payments::AuthorizationError> {
+    fn foreign_from(authorization: &storage::Authorization) -> Self {
+        let has_error = authorization.error_code.is_some() || authorization.error_message.is_some();
+
+        (authorization.status != storage_enums::AuthorizationStatus::Success && has_error).then(
+            || payments::AuthorizationError {
+                code: authorization.error_code.clone(),
+                message: authorization.error_message.clone(),
+            },
+        )
+    }
+}
+
+impl ForeignFrom<Vec<storage::Authorization>> for payments::IncrementalAuthorizationHistory {
+    fn foreign_from(mut authorizations: Vec<storage::Authorization>) -> Self {
+        authorizations.sort_by_key(|authorization| authorization.created_at);
+        let successful_authorizations = authorizations.iter().filter(|authorization| {
+            authorization.status == storage_enums::AuthorizationStatus::Success
+        });
+        let successful_authorizations_count = successful_authorizations.clone().count();
+        // Each incremental authorization carries the total amount authorized for the payment
+        let total_authorized_amount = successful_authorizations
+            .last()
+            .map(|authorization| authorization.amount);
+        Self {
+            authorizations: authorizations
+                .into_iter()
+                .map(payments::IncrementalAuthorizationResponse::foreign_from)
+                .collect(),
+            total_authorized_amount,
+            successful_authorizations_count,
+        }
+    }
+}
+
 impl ForeignFrom<&storage::Authentication> for payments::ExternalAuthenticationDetailsResponse {
     fn foreign_from(authn_data: &storage::Authentication) -> Self {
         let version = authn_data
             .maximum_supported_version
             .as_ref()
             .map(|version| version.to_string());
+        let supports_3ds2 = authn_data
+            .maximum_supported_version
+            .as_ref()
+            .is_some_and(|version| version.get_major() >= 2);
+        // The ACS URL is provided only when the issuer requires the customer to complete a
+        // challenge, and is not meaningful before the authentication call is made
+        let challenge_mandated = authn_data.acs_url.is_some()
+            && authn_data.authentication_status != storage_enums::AuthenticationStatus::Started;
         Self {
             authentication_flow: authn_data.authentication_type,
             electronic_commerce_indicator: authn_data.eci.clone(),
             status: authn_data.authentication_status,
             ds_transaction_id: authn_data.threeds_server_transaction_id.clone(),
             version,
+            supports_3ds2,
+            challenge_mandated,
+            error_code: authn_data.error_code.clone(),
+            error_message: authn_data.error_message.clone(),
+        }
+    }
+}
+
+impl ForeignFrom<&storage::Authentication> for payments::AuthenticationRetrieveResponse {
+    fn foreign_from(authn_data: &storage::Authentication) -> Self {
+        Self {
+            authentication_id: authn_data.authentication_id.clone(),
+            merchant_id: authn_data.merchant_id.clone(),
+            payment_id: authn_data.payment_id.clone(),
+            profile_id: authn_data.profile_id.clone(),
+            authentication_connector: authn_data.authentication_connector.clone(),
+            authentication_flow: authn_data.authentication_type,
+            status: authn_data.authentication_status,
+            trans_status: authn_data.trans_status.clone(),
+            electronic_commerce_indicator: authn_data.eci.clone(),
+            cavv: authn_data.cavv.clone().map(Secret::new),
+            ds_transaction_id: authn_data.threeds_server_transaction_id.clone(),
+            message_version: authn_data
+                .message_version
+                .as_ref()
+                .map(|version| version.to_string()),
+            acs_url: authn_data.acs_url.clone(),
+            challenge_request: authn_data.challenge_request.clone(),
+            acs_reference_number: authn_data.acs_reference_number.clone(),
+            acs_trans_id: authn_data.acs_trans_id.clone(),
             error_code: authn_data.error_code.clone(),
             error_message: authn_data.error_message.clone(),
+            created_at: authn_data.created_at,
+            modified_at: authn_data.modified_at,
         }
     }
 }
@@ -965,6 +2127,8 @@ impl ForeignFrom<storage::Dispute> for api_models::disputes::DisputeResponsePaym
     fn foreign_from(dispute: storage::Dispute) -> Self {
         Self {
             dispute_id: dispute.dispute_id,
+            amount: common_utils::types::MinorUnit::new(dispute.dispute_amount),
+            currency: dispute.currency,
             dispute_stage: dispute.dispute_stage,
             dispute_status: dispute.dispute_status,
             connector_status: dispute.connector_status,
@@ -991,8 +2155,50 @@ impl ForeignFrom<storage::FileMetadata> for api_models::files::FileMetadataRespo
     }
 }
 
+impl ForeignFrom<storage::FileMetadata> for api_models::files::FileDownloadDescriptor {
+    fn foreign_from(file_metadata: storage::FileMetadata) -> Self {
+        let not_available_reason = (!file_metadata.available)
+            .then(|| "File has not been uploaded to the file upload provider".to_string());
+        Self {
+            file_id: file_metadata.file_id,
+            provider_file_id: file_metadata.provider_file_id,
+            file_upload_provider: file_metadata.file_upload_provider,
+            file_type: file_metadata.file_type,
+            not_available_reason,
+        }
+    }
+}
+
+const PREMIUM_CARD_SUBTYPES: &[&str] = &[
+    "platinum",
+    "business",
+    "corporate",
+    "commercial",
+    "signature",
+    "infinite",
+    "world elite",
+];
+
+const STANDARD_CARD_SUBTYPES: &[&str] = &["classic", "standard", "electron"];
+
+fn get_card_category(card_type: Option<&str>, card_subtype: Option<&str>) -> Option<String> {
+    let card_subtype = card_subtype?.trim().to_lowercase();
+    let is_commercial_card =
+        card_type.is_some_and(|card_type| card_type.trim().eq_ignore_ascii_case("commercial"));
+
+    if is_commercial_card || PREMIUM_CARD_SUBTYPES.contains(&card_subtype.as_str()) {
+        Some("premium".to_string())
+    } else if STANDARD_CARD_SUBTYPES.contains(&card_subtype.as_str()) {
+        Some("standard".to_string())
+    } else {
+        None
+    }
+}
+
 impl ForeignFrom<diesel_models::cards_info::CardInfo> for api_models::cards_info::CardInfoResponse {
     fn foreign_from(item: diesel_models::cards_info::CardInfo) -> Self {
+        let card_category =
+            get_card_category(item.card_type.as_deref(), item.card_subtype.as_deref());
         Self {
             card_iin: item.card_iin,
             card_type: item.card_type,
@@ -1000,6 +2206,99 @@ impl ForeignFrom<diesel_models::cards_info::CardInfo> for api_models::cards_info
             card_network: item.card_network.map(|x| x.to_string()),
             card_issuer: item.card_issuer,
             card_issuing_country: item.card_issuing_country,
+            card_category,
+        }
+    }
+}
+
+fn parse_payment_methods_enabled(
+    payment_methods_enabled: Option<Vec<pii::SecretSerdeValue>>,
+) -> errors::RouterResult<Option<Vec<api_models::admin::PaymentMethodsEnabled>>> {
+    payment_methods_enabled
+        .map(|secret_val| {
+            let val = secret_val
+                .into_iter()
+                .map(|secret| secret.expose())
+                .collect();
+            serde_json::Value::Array(val)
+                .parse_value("PaymentMethods")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+        })
+        .transpose()
+}
+
+/// Extracts only the top level payment methods enabled on the connector, without their types
+impl ForeignTryFrom<&domain::MerchantConnectorAccount>
+    for std::collections::HashSet<api_enums::PaymentMethod>
+{
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn foreign_try_from(item: &domain::MerchantConnectorAccount) -> Result<Self, Self::Error> {
+        Ok(
+            parse_payment_methods_enabled(item.payment_methods_enabled.clone())?
+                .unwrap_or_default()
+                .into_iter()
+                .map(|payment_methods_enabled| payment_methods_enabled.payment_method)
+                .collect(),
+        )
+    }
+}
+
+impl ForeignTryFrom<Vec<domain::MerchantConnectorAccount>>
+    for api_models::admin::PaymentMethodCapabilityMatrix
+{
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn foreign_try_from(items: Vec<domain::MerchantConnectorAccount>) -> Result<Self, Self::Error> {
+        let mut payment_methods: std::collections::HashMap<
+            api_enums::PaymentMethod,
+            Vec<common_utils::id_type::MerchantConnectorAccountId>,
+        > = std::collections::HashMap::new();
+        for item in items {
+            for payment_method in
+                std::collections::HashSet::<api_enums::PaymentMethod>::foreign_try_from(&item)?
+            {
+                payment_methods
+                    .entry(payment_method)
+                    .or_default()
+                    .push(item.get_id());
+            }
+        }
+        Ok(Self { payment_methods })
+    }
+}
+
+/// Replaces every value in the JSON with a mask, while retaining the keys and the structure
+fn mask_json_values(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.into_iter()
+                .map(|(key, value)| (key, mask_json_values(value)))
+                .collect(),
+        ),
+        serde_json::Value::Array(values) => {
+            serde_json::Value::Array(values.into_iter().map(mask_json_values).collect())
+        }
+        serde_json::Value::Null => serde_json::Value::Null,
+        serde_json::Value::Bool(_)
+        | serde_json::Value::Number(_)
+        | serde_json::Value::String(_) => serde_json::Value::String("***".to_string()),
+    }
+}
+
+impl ForeignFrom<domain::MerchantConnectorAccount>
+    for api_models::admin::MerchantConnectorMaskedResponse
+{
+    fn foreign_from(item: domain::MerchantConnectorAccount) -> Self {
+        Self {
+            merchant_connector_id: item.get_id(),
+            connector_type: item.connector_type,
+            connector_name: item.connector_name,
+            connector_label: item.connector_label,
+            profile_id: item.profile_id,
+            connector_account_details: mask_json_values(
+                item.connector_account_details.into_inner().expose(),
+            ),
+            disabled: item.disabled,
+            status: item.status,
         }
     }
 }
@@ -1009,18 +2308,7 @@ impl ForeignTryFrom<domain::MerchantConnectorAccount>
 {
     type Error = error_stack::Report<errors::ApiErrorResponse>;
     fn foreign_try_from(item: domain::MerchantConnectorAccount) -> Result<Self, Self::Error> {
-        let payment_methods_enabled = match item.payment_methods_enabled {
-            Some(secret_val) => {
-                let val = secret_val
-                    .into_iter()
-                    .map(|secret| secret.expose())
-                    .collect();
-                serde_json::Value::Array(val)
-                    .parse_value("PaymentMethods")
-                    .change_context(errors::ApiErrorResponse::InternalServerError)?
-            }
-            None => None,
-        };
+        let payment_methods_enabled = parse_payment_methods_enabled(item.payment_methods_enabled)?;
         let frm_configs = match item.frm_configs {
             Some(frm_value) => {
                 let configs_for_frm : Vec<api_models::admin::FrmConfigs> = frm_value
@@ -1127,23 +2415,24 @@ impl ForeignTryFrom<domain::MerchantConnectorAccount>
     }
 }
 
+impl ForeignFrom<&domain::MerchantConnectorAccount> for crate::types::SafeConnectorDebug {
+    fn foreign_from(merchant_connector_account: &domain::MerchantConnectorAccount) -> Self {
+        Self {
+            connector_name: merchant_connector_account.connector_name.clone(),
+            merchant_connector_id: merchant_connector_account.get_id(),
+            connector_type: merchant_connector_account.connector_type,
+        }
+    }
+}
+
 impl ForeignTryFrom<domain::MerchantConnectorAccount>
     for api_models::admin::MerchantConnectorResponse
 {
     type Error = error_stack::Report<errors::ApiErrorResponse>;
     fn foreign_try_from(item: domain::MerchantConnectorAccount) -> Result<Self, Self::Error> {
-        let payment_methods_enabled = match item.payment_methods_enabled.clone() {
-            Some(secret_val) => {
-                let val = secret_val
-                    .into_iter()
-                    .map(|secret| secret.expose())
-                    .collect();
-                serde_json::Value::Array(val)
-                    .parse_value("PaymentMethods")
-                    .change_context(errors::ApiErrorResponse::InternalServerError)?
-            }
-            None => None,
-        };
+        let safe_connector_debug = crate::types::SafeConnectorDebug::foreign_from(&item);
+        let payment_methods_enabled =
+            parse_payment_methods_enabled(item.payment_methods_enabled.clone())?;
         let frm_configs = match item.frm_configs {
             Some(ref frm_value) => {
                 let configs_for_frm : Vec<api_models::admin::FrmConfigs> = frm_value
@@ -1169,14 +2458,24 @@ impl ForeignTryFrom<domain::MerchantConnectorAccount>
                 .into_inner()
                 .parse_value("ConnectorAuthType")
                 .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Failed while parsing value for ConnectorAuthType")?;
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Failed while parsing value for ConnectorAuthType for {:?}",
+                        safe_connector_debug
+                    )
+                })?;
         // get the masked keys from the ConnectorAuthType and encode it to secret value
         let masked_connector_account_details = Secret::new(
             connector_account_details
                 .get_masked_keys()
                 .encode_to_value()
                 .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Failed to encode ConnectorAuthType")?,
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Failed to encode ConnectorAuthType for {:?}",
+                        safe_connector_debug
+                    )
+                })?,
         );
         #[cfg(feature = "v2")]
         let response = Self {
@@ -1196,7 +2495,12 @@ impl ForeignTryFrom<domain::MerchantConnectorAccount>
                         webhook_details.expose(),
                         "MerchantConnectorWebhookDetails",
                     )
-                    .attach_printable("Unable to deserialize connector_webhook_details")
+                    .attach_printable_lazy(|| {
+                        format!(
+                            "Unable to deserialize connector_webhook_details for {:?}",
+                            safe_connector_debug
+                        )
+                    })
                     .change_context(errors::ApiErrorResponse::InternalServerError)
                 })
                 .transpose()?,
@@ -1212,7 +2516,12 @@ impl ForeignTryFrom<domain::MerchantConnectorAccount>
                         data.expose(),
                         "AdditionalMerchantData",
                     )
-                    .attach_printable("Unable to deserialize additional_merchant_data")
+                    .attach_printable_lazy(|| {
+                        format!(
+                            "Unable to deserialize additional_merchant_data for {:?}",
+                            safe_connector_debug
+                        )
+                    })
                     .change_context(errors::ApiErrorResponse::InternalServerError)
                 })
                 .transpose()?
@@ -1225,7 +2534,12 @@ impl ForeignTryFrom<domain::MerchantConnectorAccount>
                         .parse_value::<api_models::admin::ConnectorWalletDetails>(
                             "ConnectorWalletDetails",
                         )
-                        .attach_printable("Unable to deserialize connector_wallets_details")
+                        .attach_printable_lazy(|| {
+                            format!(
+                                "Unable to deserialize connector_wallets_details for {:?}",
+                                safe_connector_debug
+                            )
+                        })
                         .change_context(errors::ApiErrorResponse::InternalServerError)
                 })
                 .transpose()?,
@@ -1252,7 +2566,12 @@ impl ForeignTryFrom<domain::MerchantConnectorAccount>
                         webhook_details.expose(),
                         "MerchantConnectorWebhookDetails",
                     )
-                    .attach_printable("Unable to deserialize connector_webhook_details")
+                    .attach_printable_lazy(|| {
+                        format!(
+                            "Unable to deserialize connector_webhook_details for {:?}",
+                            safe_connector_debug
+                        )
+                    })
                     .change_context(errors::ApiErrorResponse::InternalServerError)
                 })
                 .transpose()?,
@@ -1268,7 +2587,12 @@ impl ForeignTryFrom<domain::MerchantConnectorAccount>
                         data.expose(),
                         "AdditionalMerchantData",
                     )
-                    .attach_printable("Unable to deserialize additional_merchant_data")
+                    .attach_printable_lazy(|| {
+                        format!(
+                            "Unable to deserialize additional_merchant_data for {:?}",
+                            safe_connector_debug
+                        )
+                    })
                     .change_context(errors::ApiErrorResponse::InternalServerError)
                 })
                 .transpose()?
@@ -1281,7 +2605,12 @@ impl ForeignTryFrom<domain::MerchantConnectorAccount>
                         .parse_value::<api_models::admin::ConnectorWalletDetails>(
                             "ConnectorWalletDetails",
                         )
-                        .attach_printable("Unable to deserialize connector_wallets_details")
+                        .attach_printable_lazy(|| {
+                            format!(
+                                "Unable to deserialize connector_wallets_details for {:?}",
+                                safe_connector_debug
+                            )
+                        })
                         .change_context(errors::ApiErrorResponse::InternalServerError)
                 })
                 .transpose()?,
@@ -1290,16 +2619,129 @@ impl ForeignTryFrom<domain::MerchantConnectorAccount>
     }
 }
 
+#[cfg(feature = "v1")]
+impl ForeignFrom<&storage::PaymentAttempt> for Option<payments::AttemptErrorDetails> {
+    fn foreign_from(payment_attempt: &storage::PaymentAttempt) -> Self {
+        let has_error = payment_attempt.error_code.is_some()
+            || payment_attempt.error_reason.is_some()
+            || payment_attempt.unified_code.is_some()
+            || payment_attempt.unified_message.is_some();
+
+        has_error.then(|| payments::AttemptErrorDetails {
+            code: payment_attempt.error_code.clone(),
+            message: payment_attempt.error_reason.clone(),
+            unified_code: payment_attempt.unified_code.clone(),
+            unified_message: payment_attempt.unified_message.clone(),
+        })
+    }
+}
+
+/// Extracts the details of the initial payment required for subsequent merchant initiated
+/// payments. Returns `None` when the attempt has no transaction id at the connector.
+#[cfg(feature = "v1")]
+impl ForeignFrom<&storage::PaymentAttempt> for Option<payments::NetworkTransactionContext> {
+    fn foreign_from(payment_attempt: &storage::PaymentAttempt) -> Self {
+        let connector_transaction_id = payment_attempt.get_connector_payment_id()?.to_string();
+        let network_transaction_id = payment_attempt
+            .connector_metadata
+            .as_ref()
+            .and_then(|connector_metadata| connector_metadata.get("network_transaction_id"))
+            .and_then(serde_json::Value::as_str)
+            .map(ToString::to_string);
+        Some(payments::NetworkTransactionContext {
+            connector_transaction_id,
+            network_transaction_id,
+            connector: payment_attempt.connector.clone(),
+        })
+    }
+}
+
+/// Estimates the latency of the connector call from the time the attempt was created to the time it
+/// was last updated. Returns `None` when the attempt has not been sent to a connector, or has not
+/// been updated after creation.
+#[cfg(feature = "v1")]
+impl ForeignFrom<&storage::PaymentAttempt> for Option<payments::AttemptLatencyMetrics> {
+    fn foreign_from(payment_attempt: &storage::PaymentAttempt) -> Self {
+        let connector = payment_attempt.connector.clone()?;
+        let connector_call_duration = payment_attempt.modified_at - payment_attempt.created_at;
+        let connector_call_duration_ms =
+            u64::try_from(connector_call_duration.whole_milliseconds()).ok()?;
+
+        (connector_call_duration_ms > 0).then(|| payments::AttemptLatencyMetrics {
+            attempt_id: payment_attempt.attempt_id.clone(),
+            connector,
+            connector_call_duration_ms,
+        })
+    }
+}
+
+/// Reconstructs the browser information captured on the attempt, so that it can be reused while
+/// retrying the authentication. Returns `None` when no browser information was captured.
+#[cfg(feature = "v1")]
+impl ForeignFrom<&storage::PaymentAttempt> for Option<payments::BrowserInformation> {
+    fn foreign_from(payment_attempt: &storage::PaymentAttempt) -> Self {
+        payment_attempt
+            .browser_info
+            .clone()
+            .and_then(|browser_info| {
+                browser_info
+                    .parse_value::<payments::BrowserInformation>("BrowserInformation")
+                    .ok()
+            })
+            .filter(|browser_info| {
+                browser_info.user_agent.is_some()
+                    || browser_info.accept_header.is_some()
+                    || browser_info.language.is_some()
+                    || browser_info.screen_height.is_some()
+                    || browser_info.screen_width.is_some()
+                    || browser_info.color_depth.is_some()
+                    || browser_info.time_zone.is_some()
+                    || browser_info.ip_address.is_some()
+                    || browser_info.java_enabled.is_some()
+                    || browser_info.java_script_enabled.is_some()
+            })
+    }
+}
+
 #[cfg(feature = "v1")]
 impl ForeignFrom<storage::PaymentAttempt> for payments::PaymentAttemptResponse {
     fn foreign_from(payment_attempt: storage::PaymentAttempt) -> Self {
         let connector_transaction_id = payment_attempt
             .get_connector_payment_id()
             .map(ToString::to_string);
+        let error_details = Option::<payments::AttemptErrorDetails>::foreign_from(&payment_attempt);
+        // Connector metadata is provider specific, so shapes that are not recognized are left out
+        let parsed_connector_metadata = payment_attempt
+            .connector_metadata
+            .clone()
+            .and_then(|connector_metadata| {
+                connector_metadata
+                    .parse_value::<payments::ConnectorMetadataSummary>("ConnectorMetadataSummary")
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to parse connector metadata summary")
+                    .ok()
+            })
+            .filter(|summary| !summary.is_empty());
+        // The issuer is looked up from the card info when the attempt is created, and stored along
+        // with the additional payment method data
+        let card_issuer = payment_attempt
+            .payment_method_data
+            .clone()
+            .and_then(|payment_method_data| {
+                payment_method_data
+                    .parse_value::<payments::AdditionalPaymentData>("AdditionalPaymentData")
+                    .ok()
+            })
+            .and_then(|additional_payment_data| match additional_payment_data {
+                payments::AdditionalPaymentData::Card(card_info) => card_info.card_issuer,
+                _ => None,
+            });
         Self {
             attempt_id: payment_attempt.attempt_id,
             status: payment_attempt.status,
             amount: payment_attempt.net_amount.get_order_amount(),
+            surcharge_amount: payment_attempt.net_amount.get_surcharge_amount(),
+            tax_on_surcharge_amount: payment_attempt.net_amount.get_tax_on_surcharge(),
             currency: payment_attempt.currency,
             connector: payment_attempt.connector,
             error_message: payment_attempt.error_reason,
@@ -1314,13 +2756,41 @@ impl ForeignFrom<storage::PaymentAttempt> for payments::PaymentAttemptResponse {
             error_code: payment_attempt.error_code,
             payment_token: payment_attempt.payment_token,
             connector_metadata: payment_attempt.connector_metadata,
+            parsed_connector_metadata,
             payment_experience: payment_attempt.payment_experience,
             payment_method_type: payment_attempt.payment_method_type,
+            card_issuer,
             reference_id: payment_attempt.connector_response_reference_id,
             unified_code: payment_attempt.unified_code,
             unified_message: payment_attempt.unified_message,
             client_source: payment_attempt.client_source,
             client_version: payment_attempt.client_version,
+            error_details,
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ForeignFrom<Vec<storage::PaymentAttempt>> for payments::PaymentAttemptListSummary {
+    fn foreign_from(mut payment_attempts: Vec<storage::PaymentAttempt>) -> Self {
+        payment_attempts.sort_by_key(|payment_attempt| payment_attempt.created_at);
+
+        let mut connectors: Vec<String> = Vec::new();
+        for connector in payment_attempts
+            .iter()
+            .filter_map(|payment_attempt| payment_attempt.connector.as_ref())
+        {
+            if !connectors.contains(connector) {
+                connectors.push(connector.to_owned());
+            }
+        }
+
+        Self {
+            attempts_count: payment_attempts.len(),
+            connectors,
+            terminal_status: payment_attempts
+                .last()
+                .map(|payment_attempt| payment_attempt.status),
         }
     }
 }
@@ -1341,10 +2811,60 @@ impl ForeignFrom<storage::Capture> for payments::CaptureResponse {
             error_code: capture.error_code,
             error_reason: capture.error_reason,
             reference_id: capture.connector_response_reference_id,
+            profile_id: capture.profile_id,
+            merchant_connector_id: capture.merchant_connector_id,
+            created_at: capture.created_at,
         }
     }
 }
 
+impl ForeignTryFrom<Vec<storage::Capture>> for payments::CaptureSummaryResponse {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn foreign_try_from(captures: Vec<storage::Capture>) -> errors::RouterResult<Self> {
+        let currencies = captures
+            .iter()
+            .filter_map(|capture| capture.currency)
+            .collect::<std::collections::HashSet<_>>();
+        when(currencies.len() > 1, || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "All the captures of a payment must be made in the same currency"
+                    .to_string(),
+            }))
+        })?;
+
+        let total_captured_amount = captures
+            .iter()
+            .filter(|capture| capture.status == storage_enums::CaptureStatus::Charged)
+            .map(|capture| capture.amount)
+            .sum();
+        let successful_captures_count = captures
+            .iter()
+            .filter(|capture| capture.status == storage_enums::CaptureStatus::Charged)
+            .count();
+        let failed_captures_count = captures
+            .iter()
+            .filter(|capture| capture.status == storage_enums::CaptureStatus::Failed)
+            .count();
+        let latest_capture_status = captures
+            .iter()
+            .max_by_key(|capture| capture.capture_sequence)
+            .map(|capture| capture.status);
+
+        Ok(Self {
+            total_captured_amount,
+            currency: currencies.into_iter().next(),
+            successful_captures_count,
+            failed_captures_count,
+            latest_capture_status,
+            captures: captures
+                .into_iter()
+                .map(payments::CaptureResponse::foreign_from)
+                .collect(),
+        })
+    }
+}
+
 #[cfg(feature = "payouts")]
 impl ForeignFrom<api_models::payouts::PayoutMethodData> for api_enums::PaymentMethodType {
     fn foreign_from(value: api_models::payouts::PayoutMethodData) -> Self {
@@ -1356,6 +2876,67 @@ impl ForeignFrom<api_models::payouts::PayoutMethodData> for api_enums::PaymentMe
     }
 }
 
+#[cfg(feature = "payouts")]
+impl ForeignFrom<&api_models::payouts::PayoutMethodData>
+    for api_models::payouts::PayoutMethodSummary
+{
+    fn foreign_from(value: &api_models::payouts::PayoutMethodData) -> Self {
+        use api_models::payouts::{Bank, PayoutMethodData, PayoutMethodMaskedIdentifier, Wallet};
+
+        let masked_identifier = match value {
+            PayoutMethodData::Card(card) => Some(PayoutMethodMaskedIdentifier::CardLast4(
+                card.card_number.get_last4(),
+            )),
+            PayoutMethodData::Bank(bank) => {
+                let account_number = match bank {
+                    Bank::Ach(ach) => &ach.bank_account_number,
+                    Bank::Bacs(bacs) => &bacs.bank_account_number,
+                    Bank::Sepa(sepa) => &sepa.iban,
+                    Bank::Pix(pix) => &pix.bank_account_number,
+                };
+                Some(PayoutMethodMaskedIdentifier::BankAccountLast4(
+                    get_last4_characters(account_number.peek()),
+                ))
+            }
+            PayoutMethodData::Wallet(Wallet::Paypal(paypal)) => paypal
+                .email
+                .clone()
+                .map(|email| {
+                    PayoutMethodMaskedIdentifier::WalletEmail(
+                        common_utils::transformers::ForeignFrom::foreign_from(email),
+                    )
+                })
+                .or_else(|| {
+                    paypal.telephone_number.clone().map(|telephone_number| {
+                        PayoutMethodMaskedIdentifier::WalletTelephoneNumber(telephone_number.into())
+                    })
+                })
+                .or_else(|| {
+                    paypal
+                        .paypal_id
+                        .clone()
+                        .map(|paypal_id| PayoutMethodMaskedIdentifier::WalletId(paypal_id.into()))
+                }),
+            PayoutMethodData::Wallet(Wallet::Venmo(venmo)) => {
+                venmo.telephone_number.clone().map(|telephone_number| {
+                    PayoutMethodMaskedIdentifier::WalletTelephoneNumber(telephone_number.into())
+                })
+            }
+        };
+
+        Self {
+            payout_method_type: api_enums::PaymentMethodType::foreign_from(value.clone()),
+            masked_identifier,
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+fn get_last4_characters(value: &str) -> String {
+    let char_count = value.chars().count();
+    value.chars().skip(char_count.saturating_sub(4)).collect()
+}
+
 #[cfg(feature = "payouts")]
 impl ForeignFrom<api_models::payouts::Bank> for api_enums::PaymentMethodType {
     fn foreign_from(value: api_models::payouts::Bank) -> Self {
@@ -1400,6 +2981,16 @@ impl ForeignFrom<api_models::enums::PayoutType> for api_enums::PaymentMethod {
     }
 }
 
+/// Resolves the source of a payment confirmation when the `X_PAYMENT_CONFIRM_SOURCE` header is
+/// absent. The dashboard and the SDK always send the header, so a missing header means the call
+/// was made by the merchant's server. Internal-only sources (`Webhook`, `ExternalAuthenticator`)
+/// never reach this point, as they are rejected while parsing the `HeaderPayload`.
+impl ForeignFrom<Option<api_enums::PaymentSource>> for api_enums::PaymentSource {
+    fn foreign_from(payment_confirm_source: Option<api_enums::PaymentSource>) -> Self {
+        payment_confirm_source.unwrap_or(Self::MerchantServer)
+    }
+}
+
 #[cfg(feature = "v1")]
 impl ForeignTryFrom<&HeaderMap> for hyperswitch_domain_models::payments::HeaderPayload {
     type Error = error_stack::Report<errors::ApiErrorResponse>;
@@ -1431,8 +3022,17 @@ impl ForeignTryFrom<&HeaderMap> for hyperswitch_domain_models::payments::HeaderP
         )?;
         let locale =
             get_header_value_by_key(ACCEPT_LANGUAGE.into(), headers)?.map(|val| val.to_string());
-        let x_hs_latency = get_header_value_by_key(X_HS_LATENCY.into(), headers)
-            .map(|value| value == Some("true"))
+        let x_hs_latency = get_header_value_by_key(X_HS_LATENCY.into(), headers)?
+            .map(|value| {
+                value
+                    .to_ascii_lowercase()
+                    .parse::<bool>()
+                    .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                        message: "Invalid data received in x-hs-latency header".into(),
+                    })
+                    .attach_printable("Failed while parsing x-hs-latency header value to bool")
+            })
+            .transpose()?
             .unwrap_or(false);
 
         let client_source =
@@ -1453,12 +3053,16 @@ impl ForeignTryFrom<&HeaderMap> for hyperswitch_domain_models::payments::HeaderP
         let x_client_platform_str =
             get_header_value_by_key(X_CLIENT_PLATFORM.into(), headers)?.map(|val| val.to_string());
 
-        let x_client_platform: Option<api_enums::ClientPlatform> =
-            x_client_platform_str.map(|x_client_platform| {
+        let x_client_platform: Option<api_enums::ClientPlatform> = x_client_platform_str
+            .map(|x_client_platform| {
                 x_client_platform
                     .parse_enum("ClientPlatform")
-                    .unwrap_or(api_enums::ClientPlatform::Unknown)
-            });
+                    .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                        message: "Invalid data received in x-client-platform header".into(),
+                    })
+                    .attach_printable("Failed while parsing ClientPlatform header value to enum")
+            })
+            .transpose()?;
 
         let x_merchant_domain =
             get_header_value_by_key(X_MERCHANT_DOMAIN.into(), headers)?.map(|val| val.to_string());
@@ -1519,8 +3123,17 @@ impl ForeignTryFrom<&HeaderMap> for hyperswitch_domain_models::payments::HeaderP
         )?;
         let locale =
             get_header_value_by_key(ACCEPT_LANGUAGE.into(), headers)?.map(|val| val.to_string());
-        let x_hs_latency = get_header_value_by_key(X_HS_LATENCY.into(), headers)
-            .map(|value| value == Some("true"))
+        let x_hs_latency = get_header_value_by_key(X_HS_LATENCY.into(), headers)?
+            .map(|value| {
+                value
+                    .to_ascii_lowercase()
+                    .parse::<bool>()
+                    .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                        message: "Invalid data received in x-hs-latency header".into(),
+                    })
+                    .attach_printable("Failed while parsing x-hs-latency header value to bool")
+            })
+            .transpose()?
             .unwrap_or(false);
 
         let client_source =
@@ -1541,12 +3154,16 @@ impl ForeignTryFrom<&HeaderMap> for hyperswitch_domain_models::payments::HeaderP
         let x_client_platform_str =
             get_header_value_by_key(X_CLIENT_PLATFORM.into(), headers)?.map(|val| val.to_string());
 
-        let x_client_platform: Option<api_enums::ClientPlatform> =
-            x_client_platform_str.map(|x_client_platform| {
+        let x_client_platform: Option<api_enums::ClientPlatform> = x_client_platform_str
+            .map(|x_client_platform| {
                 x_client_platform
                     .parse_enum("ClientPlatform")
-                    .unwrap_or(api_enums::ClientPlatform::Unknown)
-            });
+                    .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                        message: "Invalid data received in x-client-platform header".into(),
+                    })
+                    .attach_printable("Failed while parsing ClientPlatform header value to enum")
+            })
+            .transpose()?;
 
         let x_merchant_domain =
             get_header_value_by_key(X_MERCHANT_DOMAIN.into(), headers)?.map(|val| val.to_string());
@@ -1664,17 +3281,48 @@ impl
             name: customer
                 .and_then(|cust| cust.name.as_ref().map(|n| n.clone().into_inner()))
                 .or(customer_details_from_pi.clone().and_then(|cd| cd.name)),
+            payment_method: payment_attempt.and_then(|pa| pa.payment_method),
+            payment_method_type: payment_attempt.and_then(|pa| pa.payment_method_type),
+            capture_method: payment_attempt.and_then(|pa| pa.capture_method),
             ..Self::default()
         })
     }
 }
 
+/// A payment link expires at its fulfilment time, or after the default session expiry from its
+/// creation when no fulfilment time was set.
+impl ForeignFrom<&storage::PaymentLink> for payments::PaymentLinkStatus {
+    fn foreign_from(payment_link: &storage::PaymentLink) -> Self {
+        let session_expiry = payment_link.fulfilment_time.unwrap_or_else(|| {
+            payment_link
+                .created_at
+                .saturating_add(time::Duration::seconds(
+                    crate::consts::DEFAULT_SESSION_EXPIRY,
+                ))
+        });
+        crate::core::payment_link::check_payment_link_status(session_expiry)
+    }
+}
+
+impl ForeignFrom<storage::PaymentLink> for payments::RetrievePaymentLinkResponse {
+    fn foreign_from(payment_link: storage::PaymentLink) -> Self {
+        let status = payments::PaymentLinkStatus::foreign_from(&payment_link);
+        Self::foreign_from((payment_link, status))
+    }
+}
+
 impl ForeignFrom<(storage::PaymentLink, payments::PaymentLinkStatus)>
     for payments::RetrievePaymentLinkResponse
 {
     fn foreign_from(
         (payment_link_config, status): (storage::PaymentLink, payments::PaymentLinkStatus),
     ) -> Self {
+        let seconds_until_expiry = payment_link_config.fulfilment_time.map(|expiry| {
+            (expiry - common_utils::date_time::now())
+                .whole_seconds()
+                .max(0)
+        });
+
         Self {
             payment_link_id: payment_link_config.payment_link_id,
             merchant_id: payment_link_config.merchant_id,
@@ -1682,6 +3330,7 @@ impl ForeignFrom<(storage::PaymentLink, payments::PaymentLinkStatus)>
             amount: payment_link_config.amount,
             created_at: payment_link_config.created_at,
             expiry: payment_link_config.fulfilment_time,
+            seconds_until_expiry,
             description: payment_link_config.description,
             currency: payment_link_config.currency,
             status,
@@ -1690,6 +3339,17 @@ impl ForeignFrom<(storage::PaymentLink, payments::PaymentLinkStatus)>
     }
 }
 
+impl ForeignFrom<storage::PaymentLink> for payments::PaymentLinkQrPayload {
+    fn foreign_from(payment_link: storage::PaymentLink) -> Self {
+        Self {
+            payment_link_id: payment_link.payment_link_id,
+            link_to_pay: payment_link.link_to_pay,
+            amount: payment_link.amount,
+            currency: payment_link.currency,
+        }
+    }
+}
+
 impl From<domain::Address> for payments::AddressDetails {
     fn from(addr: domain::Address) -> Self {
         Self {
@@ -1706,6 +3366,64 @@ impl From<domain::Address> for payments::AddressDetails {
     }
 }
 
+impl ForeignFrom<&routing_types::RoutingAlgorithm> for Vec<common_enums::RoutableConnectors> {
+    fn foreign_from(routing_algorithm: &routing_types::RoutingAlgorithm) -> Self {
+        match routing_algorithm {
+            routing_types::RoutingAlgorithm::Single(connector_choice) => {
+                vec![connector_choice.connector]
+            }
+            routing_types::RoutingAlgorithm::Priority(connector_choices) => connector_choices
+                .iter()
+                .map(|connector_choice| connector_choice.connector)
+                .collect(),
+            routing_types::RoutingAlgorithm::VolumeSplit(splits) => splits
+                .iter()
+                .map(|split| split.connector.connector)
+                .collect(),
+            // The connectors of each rule are listed in the order the rules are declared,
+            // followed by the default selection, without repeating a connector
+            routing_types::RoutingAlgorithm::Advanced(program) => {
+                let mut connectors = Self::new();
+                for connector_choice in program
+                    .rules
+                    .iter()
+                    .flat_map(|rule| rule.connector_selection.get_connector_list())
+                    .chain(program.default_selection.get_connector_list())
+                {
+                    if !connectors.contains(&connector_choice.connector) {
+                        connectors.push(connector_choice.connector);
+                    }
+                }
+                connectors
+            }
+        }
+    }
+}
+
+/// Builds the fallback chain for a primary connector from the default fallback list of the
+/// profile. The primary connector is always tried first, followed by the connectors of the
+/// fallback list in their configured order, without repeating a connector.
+impl
+    ForeignFrom<(
+        common_enums::RoutableConnectors,
+        &[routing_types::RoutableConnectorChoice],
+    )> for Vec<common_enums::RoutableConnectors>
+{
+    fn foreign_from(
+        (primary_connector, default_fallback): (
+            common_enums::RoutableConnectors,
+            &[routing_types::RoutableConnectorChoice],
+        ),
+    ) -> Self {
+        let mut seen_connectors = std::collections::HashSet::new();
+        std::iter::once(primary_connector)
+            .chain(default_fallback.iter().map(|choice| choice.connector))
+            .filter(|connector| seen_connectors.insert(*connector))
+            .collect()
+    }
+}
+
+/// Used when executing a routing algorithm, which has already been validated when it was configured
 impl ForeignFrom<ConnectorSelection> for routing_types::RoutingAlgorithm {
     fn foreign_from(value: ConnectorSelection) -> Self {
         match value {
@@ -1716,11 +3434,84 @@ impl ForeignFrom<ConnectorSelection> for routing_types::RoutingAlgorithm {
     }
 }
 
+/// Used when configuring a routing algorithm, to reject volume splits which do not add up to 100
+impl ForeignTryFrom<ConnectorSelection> for routing_types::RoutingAlgorithm {
+    type Error = error_stack::Report<common_utils::errors::ValidationError>;
+
+    fn foreign_try_from(value: ConnectorSelection) -> Result<Self, Self::Error> {
+        match value {
+            ConnectorSelection::Priority(connectors) => Ok(Self::Priority(connectors)),
+
+            ConnectorSelection::VolumeSplit(splits) => {
+                let total_split = get_total_volume_split(&splits);
+                when(total_split != 100, || {
+                    Err(report!(
+                        common_utils::errors::ValidationError::InvalidValue {
+                            message: format!(
+                                "Sum of volume split percentages must be 100, found {total_split}"
+                            ),
+                        }
+                    ))
+                })?;
+                Ok(Self::VolumeSplit(splits))
+            }
+        }
+    }
+}
+
+fn get_total_volume_split(splits: &[api_models::routing::ConnectorVolumeSplit]) -> u32 {
+    splits.iter().map(|split| u32::from(split.split)).sum()
+}
+
+impl ForeignFrom<&ConnectorSelection> for api_models::routing::RoutingPreview {
+    fn foreign_from(connector_selection: &ConnectorSelection) -> Self {
+        match connector_selection {
+            ConnectorSelection::Priority(connectors) => Self {
+                connectors: connectors
+                    .iter()
+                    .map(|connector| api_models::routing::RoutingPreviewConnector {
+                        connector: connector.clone(),
+                        split: None,
+                    })
+                    .collect(),
+                warning: None,
+            },
+            ConnectorSelection::VolumeSplit(splits) => {
+                let total_split = get_total_volume_split(splits);
+                Self {
+                    connectors: splits
+                        .iter()
+                        .map(|split| api_models::routing::RoutingPreviewConnector {
+                            connector: split.connector.clone(),
+                            split: Some(split.split),
+                        })
+                        .collect(),
+                    warning: (total_split != 100).then(|| {
+                        format!("Sum of volume split percentages must be 100, found {total_split}")
+                    }),
+                }
+            }
+        }
+    }
+}
+
+impl ForeignFrom<&diesel_models::organization::OrganizationNew> for router_types::OrgCreatedEvent {
+    fn foreign_from(org_new: &diesel_models::organization::OrganizationNew) -> Self {
+        Self {
+            org_id: org_new.get_organization_id(),
+            org_name: org_new.get_organization_name(),
+            created_at: org_new.created_at,
+        }
+    }
+}
+
 impl ForeignFrom<api_models::organization::OrganizationNew>
     for diesel_models::organization::OrganizationNew
 {
     fn foreign_from(item: api_models::organization::OrganizationNew) -> Self {
-        Self::new(item.org_id, item.org_name)
+        let mut org_new_db = Self::new(item.org_id, item.org_name);
+        org_new_db.metadata = item.metadata;
+        org_new_db
     }
 }
 
@@ -1759,21 +3550,81 @@ impl ForeignFrom<gsm_api_types::GsmCreateRequest> for storage::GatewayStatusMapp
     }
 }
 
-impl ForeignFrom<storage::GatewayStatusMap> for gsm_api_types::GsmResponse {
-    fn foreign_from(value: storage::GatewayStatusMap) -> Self {
-        Self {
+impl ForeignTryFrom<Vec<gsm_api_types::GsmCreateRequest>>
+    for Vec<storage::GatewayStatusMappingNew>
+{
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn foreign_try_from(rows: Vec<gsm_api_types::GsmCreateRequest>) -> Result<Self, Self::Error> {
+        let mut seen_keys = std::collections::HashSet::with_capacity(rows.len());
+        rows.into_iter()
+            .map(storage::GatewayStatusMappingNew::foreign_from)
+            .map(|gsm| {
+                let key = (
+                    gsm.connector.clone(),
+                    gsm.flow.clone(),
+                    gsm.sub_flow.clone(),
+                    gsm.code.clone(),
+                    gsm.message.clone(),
+                );
+                if seen_keys.insert(key) {
+                    Ok(gsm)
+                } else {
+                    Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                        message: format!(
+                            "Duplicate GSM rule found for connector: {}, flow: {}, sub_flow: {}, code: {}, message: {}",
+                            gsm.connector, gsm.flow, gsm.sub_flow, gsm.code, gsm.message
+                        ),
+                    }))
+                }
+            })
+            .collect()
+    }
+}
+
+impl ForeignTryFrom<storage::GatewayStatusMap> for gsm_api_types::GsmResponse {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn foreign_try_from(value: storage::GatewayStatusMap) -> Result<Self, Self::Error> {
+        let decision = value
+            .decision
+            .clone()
+            .parse_enum("GsmDecision")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!("Invalid GSM decision stored in db: {}", value.decision)
+            })?;
+
+        Ok(Self {
             connector: value.connector.to_string(),
             flow: value.flow,
             sub_flow: value.sub_flow,
             code: value.code,
             message: value.message,
-            decision: value.decision.to_string(),
+            decision,
             status: value.status,
             router_error: value.router_error,
             step_up_possible: value.step_up_possible,
             unified_code: value.unified_code,
             unified_message: value.unified_message,
+        })
+    }
+}
+
+impl ForeignTryFrom<Vec<storage::GatewayStatusMap>> for gsm_api_types::GsmGroupedResponse {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn foreign_try_from(value: Vec<storage::GatewayStatusMap>) -> Result<Self, Self::Error> {
+        let mut connectors: std::collections::HashMap<String, Vec<gsm_api_types::GsmResponse>> =
+            std::collections::HashMap::new();
+        for gsm in value {
+            let gsm_response = gsm_api_types::GsmResponse::foreign_try_from(gsm)?;
+            connectors
+                .entry(gsm_response.connector.clone())
+                .or_default()
+                .push(gsm_response);
         }
+        Ok(Self { connectors })
     }
 }
 
@@ -1803,6 +3654,79 @@ impl ForeignFrom<&domain::Customer> for payments::CustomerDetailsResponse {
     }
 }
 
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+impl ForeignFrom<&domain::Customer> for router_types::MaskedCustomerDetails {
+    fn foreign_from(customer: &domain::Customer) -> Self {
+        const MASK: &str = "***";
+
+        let email = customer.email.as_ref().map(|email| {
+            let email = email.get_inner().peek();
+            match email.split_once('@') {
+                // Retaining the only character of the local part would reveal it entirely
+                Some((local_part, domain)) if local_part.chars().count() > 1 => {
+                    let first_char = local_part.chars().next().unwrap_or_default();
+                    format!("{first_char}{MASK}@{domain}")
+                }
+                Some((_, domain)) => format!("{MASK}@{domain}"),
+                None => MASK.to_string(),
+            }
+        });
+        let phone = customer.phone.as_ref().map(|phone| {
+            let phone = phone.get_inner().peek();
+            let length = phone.chars().count();
+            if length > 4 {
+                let last_four = phone.chars().skip(length - 4).collect::<String>();
+                format!("{MASK}{last_four}")
+            } else {
+                MASK.to_string()
+            }
+        });
+
+        Self {
+            customer_id: customer.customer_id.clone(),
+            email,
+            phone,
+        }
+    }
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+impl ForeignFrom<&domain::Customer> for api_models::customers::CustomerDataExport {
+    fn foreign_from(customer: &domain::Customer) -> Self {
+        Self::new(api_models::customers::CustomerExportDetails {
+            customer_id: customer.customer_id.clone(),
+            name: customer
+                .name
+                .as_ref()
+                .map(|name| name.get_inner().peek().to_owned()),
+            email: customer
+                .email
+                .as_ref()
+                .map(|email| email.get_inner().peek().to_owned()),
+            phone: customer
+                .phone
+                .as_ref()
+                .map(|phone| phone.get_inner().peek().to_owned()),
+            phone_country_code: customer.phone_country_code.clone(),
+            created_at: customer.created_at,
+            metadata: customer
+                .metadata
+                .as_ref()
+                .map(|metadata| metadata.peek().to_owned()),
+        })
+    }
+}
+
+impl ForeignFrom<&domain::Customer> for api_models::customers::MandateEligibility {
+    fn foreign_from(customer: &domain::Customer) -> Self {
+        Self {
+            has_email: customer.email.is_some(),
+            has_phone: customer.phone.is_some(),
+            has_name: customer.name.is_some(),
+        }
+    }
+}
+
 #[cfg(feature = "olap")]
 impl ForeignTryFrom<api_types::webhook_events::EventListConstraints>
     for api_types::webhook_events::EventListConstraintsInternal
@@ -1816,13 +3740,14 @@ impl ForeignTryFrom<api_types::webhook_events::EventListConstraints>
             && (item.created_after.is_some()
                 || item.created_before.is_some()
                 || item.limit.is_some()
-                || item.offset.is_some())
+                || item.offset.is_some()
+                || item.is_delivery_successful.is_some())
         {
             return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
-                message:
-                    "Either only `object_id` must be specified, or one or more of \
-                          `created_after`, `created_before`, `limit` and `offset` must be specified"
-                        .to_string()
+                message: "Either only `object_id` must be specified, or one or more of \
+                          `created_after`, `created_before`, `limit`, `offset` and \
+                          `is_delivery_successful` must be specified"
+                    .to_string()
             }));
         }
 
@@ -1833,6 +3758,7 @@ impl ForeignTryFrom<api_types::webhook_events::EventListConstraints>
                 created_before: item.created_before,
                 limit: item.limit.map(i64::from),
                 offset: item.offset.map(i64::from),
+                is_delivery_successful: item.is_delivery_successful,
             }),
         }
     }
@@ -1860,6 +3786,22 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventListItemRespons
             .initial_attempt_id
             .get_required_value("initial_attempt_id")
             .change_context(errors::ApiErrorResponse::InternalServerError)?;
+        // The response is stored only once the delivery has been attempted. A response that cannot
+        // be parsed should not prevent the event from being listed.
+        let response_status_code = item
+            .response
+            .as_ref()
+            .and_then(|response| {
+                response
+                    .get_inner()
+                    .peek()
+                    .parse_struct::<api_models::webhook_events::OutgoingWebhookResponseContent>(
+                        "OutgoingWebhookResponseContent",
+                    )
+                    .ok()
+            })
+            .and_then(|response| response.status_code)
+            .and_then(|status_code| i16::try_from(status_code).ok());
 
         Ok(Self {
             event_id: item.event_id,
@@ -1869,6 +3811,7 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventListItemRespons
             event_type: item.event_type,
             event_class: item.event_class,
             is_delivery_successful: item.is_webhook_notified,
+            response_status_code,
             initial_attempt_id,
             created: item.created_at,
         })
@@ -1910,10 +3853,77 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventRetrieveRespons
             request,
             response,
             delivery_attempt: item.delivery_attempt,
+            total_delivery_attempts: None,
+        })
+    }
+}
+
+impl TryFrom<domain::Event> for crate::types::ReplayableWebhookRequest {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn try_from(item: domain::Event) -> Result<Self, Self::Error> {
+        use crate::utils::OptionExt;
+
+        let request: api_models::webhook_events::OutgoingWebhookRequestContent = item
+            .request
+            .get_required_value("request")
+            .change_context(errors::ApiErrorResponse::InternalServerError)?
+            .peek()
+            .parse_struct("OutgoingWebhookRequestContent")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse webhook event request information")?;
+        let url = request
+            .url
+            .get_required_value("url")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Webhook url not found in request information of event {}",
+                    item.event_id
+                )
+            })?;
+
+        Ok(Self {
+            url,
+            headers: request.headers,
+            body: request.body,
         })
     }
 }
 
+/// `total_delivery_attempts` is counted from the given events only, so it covers every delivery
+/// attempt of an event only when all of them are passed, as the unpaginated listing of delivery
+/// attempts does.
+#[cfg(feature = "olap")]
+impl ForeignTryFrom<Vec<domain::Event>> for Vec<api_models::webhook_events::EventRetrieveResponse> {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn foreign_try_from(events: Vec<domain::Event>) -> Result<Self, Self::Error> {
+        // Delivery attempts of the same event share the same `initial_attempt_id`
+        let mut delivery_attempts_count = std::collections::HashMap::new();
+        for event in &events {
+            *delivery_attempts_count
+                .entry(event.initial_attempt_id.clone())
+                .or_insert(0) += 1;
+        }
+
+        events
+            .into_iter()
+            .map(|event| {
+                let total_delivery_attempts = delivery_attempts_count
+                    .get(&event.initial_attempt_id)
+                    .copied();
+                api_models::webhook_events::EventRetrieveResponse::try_from(event).map(|response| {
+                    api_models::webhook_events::EventRetrieveResponse {
+                        total_delivery_attempts,
+                        ..response
+                    }
+                })
+            })
+            .collect()
+    }
+}
+
 impl ForeignFrom<api_models::admin::AuthenticationConnectorDetails>
     for diesel_models::business_profile::AuthenticationConnectorDetails
 {
@@ -2080,3 +4090,6 @@ impl ForeignFrom<diesel_models::business_profile::BusinessGenericLinkConfig>
         }
     }
 }
+
+#[cfg(test)]
+mod tests;
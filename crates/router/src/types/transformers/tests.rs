@@ -0,0 +1,4191 @@
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+
+#[test]
+fn test_intent_status_to_attempt_status_round_trip() {
+    for (intent_status, attempt_status) in [
+        (
+            storage_enums::IntentStatus::Succeeded,
+            storage_enums::AttemptStatus::Charged,
+        ),
+        (
+            storage_enums::IntentStatus::RequiresCapture,
+            storage_enums::AttemptStatus::Authorized,
+        ),
+        (
+            storage_enums::IntentStatus::Cancelled,
+            storage_enums::AttemptStatus::Voided,
+        ),
+    ] {
+        let reversed = storage_enums::AttemptStatus::foreign_try_from(intent_status).unwrap();
+        assert_eq!(reversed, attempt_status);
+        assert_eq!(
+            storage_enums::IntentStatus::foreign_from(reversed),
+            intent_status
+        );
+    }
+}
+
+#[test]
+fn test_partially_captured_intent_status_is_not_reversible() {
+    assert!(storage_enums::AttemptStatus::foreign_try_from(
+        storage_enums::IntentStatus::PartiallyCaptured
+    )
+    .is_err());
+    assert!(storage_enums::AttemptStatus::foreign_try_from(
+        storage_enums::IntentStatus::PartiallyCapturedAndCapturable
+    )
+    .is_err());
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[test]
+fn test_card_details_from_stored_card_payment_method_data() {
+    let payment_method_data = serde_json::json!({
+        "Card": {
+            "last4_digits": "4242",
+            "issuer_country": "US",
+            "expiry_month": "12",
+            "expiry_year": "2030",
+            "nick_name": null,
+            "card_holder_name": null,
+            "card_isin": "424242",
+            "card_issuer": null,
+            "card_network": "Visa",
+            "card_type": "credit"
+        }
+    });
+
+    let card = get_card_details_from_payment_method_data(Some(payment_method_data)).unwrap();
+    assert_eq!(card.last4_digits, Some("4242".to_string()));
+    assert_eq!(card.card_network, Some(api_enums::CardNetwork::Visa));
+    assert_eq!(
+        card.expiry_month.map(ExposeInterface::expose),
+        Some("12".to_string())
+    );
+    assert_eq!(
+        card.expiry_year.map(ExposeInterface::expose),
+        Some("2030".to_string())
+    );
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[test]
+fn test_card_details_from_non_card_or_empty_payment_method_data() {
+    let wallet_payment_method_data = serde_json::json!({
+        "wallet": { "last4": "1234", "card_network": "Visa", "type": "debit" }
+    });
+
+    assert!(get_card_details_from_payment_method_data(Some(wallet_payment_method_data)).is_none());
+    assert!(get_card_details_from_payment_method_data(None).is_none());
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[test]
+fn test_recurring_enabled_from_connector_mandate_details() {
+    let active_mandate = serde_json::json!({
+        "mca_1": {
+            "connector_mandate_id": "mandate_1",
+            "payment_method_type": "credit",
+            "original_payment_authorized_amount": 1000,
+            "original_payment_authorized_currency": "USD",
+            "connector_mandate_status": "active"
+        }
+    });
+    let inactive_mandate = serde_json::json!({
+        "mca_1": {
+            "connector_mandate_id": "mandate_1",
+            "payment_method_type": "credit",
+            "original_payment_authorized_amount": 1000,
+            "original_payment_authorized_currency": "USD",
+            "connector_mandate_status": "inactive"
+        }
+    });
+
+    assert!(has_active_connector_mandate(Some(&active_mandate)));
+    assert!(!has_active_connector_mandate(Some(&inactive_mandate)));
+    assert!(!has_active_connector_mandate(None));
+}
+
+#[test]
+fn test_refund_status_to_event_type() {
+    for (refund_status, event_type) in [
+        (
+            storage_enums::RefundStatus::Success,
+            Some(storage_enums::EventType::RefundSucceeded),
+        ),
+        (
+            storage_enums::RefundStatus::Failure,
+            Some(storage_enums::EventType::RefundFailed),
+        ),
+        (
+            storage_enums::RefundStatus::Pending,
+            Some(storage_enums::EventType::RefundProcessing),
+        ),
+        (storage_enums::RefundStatus::ManualReview, None),
+        (storage_enums::RefundStatus::TransactionFailure, None),
+    ] {
+        assert_eq!(
+            Option::<storage_enums::EventType>::foreign_from(refund_status),
+            event_type
+        );
+    }
+}
+
+#[cfg(feature = "v1")]
+fn get_payment_attempt(
+    status: storage_enums::AttemptStatus,
+    payment_method: Option<storage_enums::PaymentMethod>,
+) -> storage::PaymentAttempt {
+    let current_time = common_utils::date_time::now();
+    storage::PaymentAttempt {
+        payment_id: common_utils::id_type::PaymentId::default(),
+        merchant_id: common_utils::id_type::MerchantId::default(),
+        attempt_id: "pay_attempt_1".to_string(),
+        status,
+        net_amount: hyperswitch_domain_models::payments::payment_attempt::NetAmount::new(
+            common_utils::types::MinorUnit::new(6540),
+            None,
+            None,
+            None,
+            None,
+        ),
+        currency: Some(storage_enums::Currency::USD),
+        save_to_locker: None,
+        connector: Some("stripe".to_string()),
+        error_message: None,
+        offer_amount: None,
+        payment_method_id: None,
+        payment_method,
+        connector_transaction_id: None,
+        capture_method: Some(storage_enums::CaptureMethod::Automatic),
+        capture_on: None,
+        confirm: true,
+        authentication_type: Some(storage_enums::AuthenticationType::NoThreeDs),
+        created_at: current_time,
+        modified_at: current_time,
+        last_synced: None,
+        cancellation_reason: None,
+        amount_to_capture: None,
+        mandate_id: None,
+        browser_info: None,
+        error_code: None,
+        payment_token: None,
+        connector_metadata: None,
+        payment_experience: None,
+        payment_method_type: Some(storage_enums::PaymentMethodType::Credit),
+        payment_method_data: None,
+        business_sub_label: None,
+        straight_through_algorithm: None,
+        preprocessing_step_id: None,
+        mandate_details: None,
+        error_reason: None,
+        multiple_capture_count: None,
+        connector_response_reference_id: None,
+        amount_capturable: common_utils::types::MinorUnit::new(0),
+        updated_by: "postgres_only".to_string(),
+        authentication_data: None,
+        encoded_data: None,
+        merchant_connector_id: None,
+        unified_code: None,
+        unified_message: None,
+        external_three_ds_authentication_attempted: None,
+        authentication_connector: None,
+        authentication_id: None,
+        mandate_data: None,
+        payment_method_billing_address_id: None,
+        fingerprint_id: None,
+        charge_id: None,
+        client_source: None,
+        client_version: None,
+        customer_acceptance: None,
+        profile_id: common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("pro_test"))
+            .unwrap(),
+        organization_id: common_utils::id_type::OrganizationId::default(),
+        connector_mandate_detail: None,
+    }
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_payments_request_from_payment_attempt() {
+    let payment_attempt = get_payment_attempt(
+        storage_enums::AttemptStatus::Charged,
+        Some(storage_enums::PaymentMethod::Card),
+    );
+
+    let request = payments::PaymentsRequest::foreign_try_from((
+        Some(&payment_attempt),
+        None,
+        None,
+        None,
+        None,
+    ))
+    .unwrap();
+    assert_eq!(request.payment_method, Some(api_enums::PaymentMethod::Card));
+    assert_eq!(
+        request.payment_method_type,
+        Some(api_enums::PaymentMethodType::Credit)
+    );
+    assert_eq!(
+        request.capture_method,
+        Some(api_enums::CaptureMethod::Automatic)
+    );
+
+    let request =
+        payments::PaymentsRequest::foreign_try_from((None, None, None, None, None)).unwrap();
+    assert_eq!(request.payment_method, None);
+    assert_eq!(request.payment_method_type, None);
+    assert_eq!(request.capture_method, None);
+}
+
+fn get_capture(
+    capture_sequence: i16,
+    status: storage_enums::CaptureStatus,
+    amount: i64,
+    currency: storage_enums::Currency,
+) -> storage::Capture {
+    let current_time = common_utils::date_time::now();
+    storage::Capture {
+        capture_id: format!("capture_{capture_sequence}"),
+        payment_id: common_utils::id_type::PaymentId::default(),
+        merchant_id: common_utils::id_type::MerchantId::default(),
+        status,
+        amount: common_utils::types::MinorUnit::new(amount),
+        currency: Some(currency),
+        connector: "stripe".to_string(),
+        error_message: None,
+        error_code: None,
+        error_reason: None,
+        tax_amount: None,
+        created_at: current_time,
+        modified_at: current_time,
+        authorized_attempt_id: "pay_attempt_1".to_string(),
+        connector_capture_id: None,
+        capture_sequence,
+        connector_response_reference_id: None,
+        connector_capture_data: None,
+        profile_id: None,
+        merchant_connector_id: None,
+    }
+}
+
+#[test]
+fn test_capture_summary_for_charged_captures() {
+    let captures = vec![
+        get_capture(
+            1,
+            storage_enums::CaptureStatus::Charged,
+            1000,
+            storage_enums::Currency::USD,
+        ),
+        get_capture(
+            2,
+            storage_enums::CaptureStatus::Charged,
+            500,
+            storage_enums::Currency::USD,
+        ),
+    ];
+
+    let summary = payments::CaptureSummaryResponse::foreign_try_from(captures).unwrap();
+    assert_eq!(
+        summary.total_captured_amount,
+        common_utils::types::MinorUnit::new(1500)
+    );
+    assert_eq!(summary.currency, Some(storage_enums::Currency::USD));
+    assert_eq!(summary.successful_captures_count, 2);
+    assert_eq!(summary.failed_captures_count, 0);
+    assert_eq!(
+        summary.latest_capture_status,
+        Some(storage_enums::CaptureStatus::Charged)
+    );
+    assert_eq!(summary.captures.len(), 2);
+}
+
+#[test]
+fn test_capture_summary_for_mixed_captures() {
+    let captures = vec![
+        get_capture(
+            1,
+            storage_enums::CaptureStatus::Charged,
+            1000,
+            storage_enums::Currency::USD,
+        ),
+        get_capture(
+            2,
+            storage_enums::CaptureStatus::Failed,
+            500,
+            storage_enums::Currency::USD,
+        ),
+    ];
+
+    let summary = payments::CaptureSummaryResponse::foreign_try_from(captures).unwrap();
+    assert_eq!(
+        summary.total_captured_amount,
+        common_utils::types::MinorUnit::new(1000)
+    );
+    assert_eq!(summary.successful_captures_count, 1);
+    assert_eq!(summary.failed_captures_count, 1);
+    assert_eq!(
+        summary.latest_capture_status,
+        Some(storage_enums::CaptureStatus::Failed)
+    );
+}
+
+#[test]
+fn test_capture_summary_for_multiple_currencies() {
+    let captures = vec![
+        get_capture(
+            1,
+            storage_enums::CaptureStatus::Charged,
+            1000,
+            storage_enums::Currency::USD,
+        ),
+        get_capture(
+            2,
+            storage_enums::CaptureStatus::Charged,
+            500,
+            storage_enums::Currency::EUR,
+        ),
+    ];
+
+    assert!(payments::CaptureSummaryResponse::foreign_try_from(captures).is_err());
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_header_payload_client_platform() {
+    use actix_web::http::header::HeaderValue;
+
+    let mut headers = HeaderMap::new();
+    headers.insert(X_CLIENT_PLATFORM, HeaderValue::from_static("ios"));
+    let header_payload =
+        hyperswitch_domain_models::payments::HeaderPayload::foreign_try_from(&headers).unwrap();
+    assert!(matches!(
+        header_payload.x_client_platform,
+        Some(api_enums::ClientPlatform::Ios)
+    ));
+
+    let mut headers = HeaderMap::new();
+    headers.insert(X_CLIENT_PLATFORM, HeaderValue::from_static("blackberry"));
+    assert!(
+        hyperswitch_domain_models::payments::HeaderPayload::foreign_try_from(&headers).is_err()
+    );
+
+    let header_payload =
+        hyperswitch_domain_models::payments::HeaderPayload::foreign_try_from(&HeaderMap::new())
+            .unwrap();
+    assert!(header_payload.x_client_platform.is_none());
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_header_payload_x_hs_latency() {
+    use actix_web::http::header::HeaderValue;
+
+    for (header_value, x_hs_latency) in [("true", true), ("TRUE", true), ("false", false)] {
+        let mut headers = HeaderMap::new();
+        headers.insert(X_HS_LATENCY, HeaderValue::from_static(header_value));
+        let header_payload =
+            hyperswitch_domain_models::payments::HeaderPayload::foreign_try_from(&headers).unwrap();
+        assert_eq!(header_payload.x_hs_latency, Some(x_hs_latency));
+    }
+
+    let mut headers = HeaderMap::new();
+    headers.insert(X_HS_LATENCY, HeaderValue::from_static("Ture"));
+    assert!(
+        hyperswitch_domain_models::payments::HeaderPayload::foreign_try_from(&headers).is_err()
+    );
+
+    let header_payload =
+        hyperswitch_domain_models::payments::HeaderPayload::foreign_try_from(&HeaderMap::new())
+            .unwrap();
+    assert_eq!(header_payload.x_hs_latency, Some(false));
+}
+
+#[test]
+fn test_connector_to_routable_connector_for_all_connectors() {
+    use strum::IntoEnumIterator;
+
+    for connector in api_enums::Connector::iter() {
+        let routable_connector = common_enums::RoutableConnectors::foreign_try_from(connector);
+        if matches!(
+            connector,
+            api_enums::Connector::Gpayments
+                | api_enums::Connector::Netcetera
+                | api_enums::Connector::Signifyd
+                | api_enums::Connector::Riskified
+                | api_enums::Connector::Threedsecureio
+                | api_enums::Connector::Taxjar
+        ) {
+            assert!(routable_connector.is_err());
+        } else {
+            assert_eq!(
+                routable_connector.unwrap().to_string(),
+                connector.to_string()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_every_routable_connector_is_converted_from_a_connector() {
+    use strum::IntoEnumIterator;
+
+    let converted_connectors = api_enums::Connector::iter()
+        .filter_map(|connector| common_enums::RoutableConnectors::foreign_try_from(connector).ok())
+        .collect::<std::collections::HashSet<_>>();
+
+    // Fraud check connectors are listed as routable connectors, but the conversion rejects them
+    for routable_connector in common_enums::RoutableConnectors::iter().filter(|connector| {
+        !matches!(
+            connector,
+            common_enums::RoutableConnectors::Signifyd
+                | common_enums::RoutableConnectors::Riskified
+        )
+    }) {
+        assert!(
+            converted_connectors.contains(&routable_connector),
+            "no connector is converted to {routable_connector}"
+        );
+    }
+}
+
+#[test]
+fn test_dispute_under_review_webhook_to_event_type() {
+    let dispute_status = storage_enums::DisputeStatus::foreign_try_from(
+        api_models::webhooks::IncomingWebhookEvent::DisputeUnderReview,
+    )
+    .unwrap();
+    assert_eq!(
+        dispute_status,
+        storage_enums::DisputeStatus::DisputeUnderReview
+    );
+    assert_eq!(
+        storage_enums::EventType::foreign_from(dispute_status),
+        storage_enums::EventType::DisputeUnderReview
+    );
+}
+
+fn get_dispute(evidence: serde_json::Value) -> storage::Dispute {
+    let current_time = common_utils::date_time::now();
+    storage::Dispute {
+        dispute_id: "dispute_1".to_string(),
+        amount: "6540".to_string(),
+        currency: "USD".to_string(),
+        dispute_stage: storage_enums::DisputeStage::Dispute,
+        dispute_status: storage_enums::DisputeStatus::DisputeOpened,
+        payment_id: common_utils::id_type::PaymentId::default(),
+        attempt_id: "pay_attempt_1".to_string(),
+        merchant_id: common_utils::id_type::MerchantId::default(),
+        connector_status: "needs_response".to_string(),
+        connector_dispute_id: "dp_1".to_string(),
+        connector_reason: None,
+        connector_reason_code: None,
+        challenge_required_by: None,
+        connector_created_at: None,
+        connector_updated_at: None,
+        created_at: current_time,
+        modified_at: current_time,
+        connector: "stripe".to_string(),
+        evidence: Secret::new(evidence),
+        profile_id: None,
+        merchant_connector_id: None,
+        dispute_amount: 6540,
+        organization_id: common_utils::id_type::OrganizationId::default(),
+    }
+}
+
+#[test]
+fn test_dispute_response_with_evidence() {
+    let evidence = serde_json::json!({ "receipt": "file_1" });
+
+    let dispute_response =
+        api_models::disputes::DisputeResponse::foreign_from(get_dispute(evidence.clone()));
+    assert_eq!(dispute_response.evidence, Some(evidence));
+}
+
+#[test]
+fn test_dispute_response_without_evidence() {
+    let dispute_response =
+        api_models::disputes::DisputeResponse::foreign_from(get_dispute(serde_json::json!({})));
+    assert_eq!(dispute_response.evidence, None);
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_payment_attempt_list_summary() {
+    let summary = payments::PaymentAttemptListSummary::foreign_from(Vec::new());
+    assert_eq!(summary, payments::PaymentAttemptListSummary::default());
+
+    let payment_attempt = get_payment_attempt(
+        storage_enums::AttemptStatus::Charged,
+        Some(storage_enums::PaymentMethod::Card),
+    );
+    let summary = payments::PaymentAttemptListSummary::foreign_from(vec![payment_attempt]);
+    assert_eq!(summary.attempts_count, 1);
+    assert_eq!(summary.connectors, vec!["stripe".to_string()]);
+    assert_eq!(
+        summary.terminal_status,
+        Some(storage_enums::AttemptStatus::Charged)
+    );
+
+    let first_attempt = get_payment_attempt(
+        storage_enums::AttemptStatus::Failure,
+        Some(storage_enums::PaymentMethod::Card),
+    );
+    let second_attempt = storage::PaymentAttempt {
+        created_at: first_attempt.created_at + time::Duration::seconds(1),
+        ..get_payment_attempt(
+            storage_enums::AttemptStatus::Failure,
+            Some(storage_enums::PaymentMethod::Card),
+        )
+    };
+    let third_attempt = storage::PaymentAttempt {
+        connector: Some("adyen".to_string()),
+        created_at: first_attempt.created_at + time::Duration::seconds(2),
+        ..get_payment_attempt(
+            storage_enums::AttemptStatus::Charged,
+            Some(storage_enums::PaymentMethod::Card),
+        )
+    };
+
+    let summary = payments::PaymentAttemptListSummary::foreign_from(vec![
+        third_attempt,
+        first_attempt,
+        second_attempt,
+    ]);
+    assert_eq!(summary.attempts_count, 3);
+    assert_eq!(
+        summary.connectors,
+        vec!["stripe".to_string(), "adyen".to_string()]
+    );
+    assert_eq!(
+        summary.terminal_status,
+        Some(storage_enums::AttemptStatus::Charged)
+    );
+}
+
+#[test]
+fn test_dispute_response_amount() {
+    let dispute_response =
+        api_models::disputes::DisputeResponse::foreign_from(get_dispute(serde_json::json!({})));
+    assert_eq!(
+        dispute_response.amount,
+        common_utils::types::MinorUnit::new(6540)
+    );
+    assert_eq!(dispute_response.currency, "USD");
+
+    let dispute = storage::Dispute {
+        amount: "0".to_string(),
+        dispute_amount: 0,
+        ..get_dispute(serde_json::json!({}))
+    };
+    let dispute_response =
+        api_models::disputes::DisputeResponsePaymentsRetrieve::foreign_from(dispute);
+    assert_eq!(
+        serde_json::to_value(&dispute_response).unwrap()["amount"],
+        serde_json::json!(0)
+    );
+}
+
+fn get_authentication() -> storage::Authentication {
+    let current_time = common_utils::date_time::now();
+    storage::Authentication {
+        authentication_id: "authn_1".to_string(),
+        merchant_id: common_utils::id_type::MerchantId::default(),
+        authentication_connector: "netcetera".to_string(),
+        connector_authentication_id: None,
+        authentication_data: None,
+        payment_method_id: "pm_1".to_string(),
+        authentication_type: None,
+        authentication_status: storage_enums::AuthenticationStatus::Started,
+        authentication_lifecycle_status: storage_enums::AuthenticationLifecycleStatus::Unused,
+        created_at: current_time,
+        modified_at: current_time,
+        error_message: None,
+        error_code: None,
+        connector_metadata: None,
+        maximum_supported_version: None,
+        threeds_server_transaction_id: None,
+        cavv: None,
+        authentication_flow_type: None,
+        message_version: None,
+        eci: None,
+        trans_status: None,
+        acquirer_bin: None,
+        acquirer_merchant_id: None,
+        three_ds_method_data: None,
+        three_ds_method_url: None,
+        acs_url: None,
+        challenge_request: None,
+        acs_reference_number: None,
+        acs_trans_id: None,
+        acs_signed_content: None,
+        profile_id: common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("pro_test"))
+            .unwrap(),
+        payment_id: None,
+        merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId::wrap(
+            "mca_test".to_string(),
+        )
+        .unwrap(),
+        ds_trans_id: None,
+        directory_server_id: None,
+        acquirer_country_code: None,
+    }
+}
+
+#[test]
+fn test_authentication_retrieve_response_for_complete_authentication() {
+    let authentication = storage::Authentication {
+        authentication_type: Some(storage_enums::DecoupledAuthenticationType::Challenge),
+        authentication_status: storage_enums::AuthenticationStatus::Success,
+        threeds_server_transaction_id: Some("threeds_server_trans_1".to_string()),
+        cavv: Some("jJ81HADVRtXfCBATEp01CJUAAAA=".to_string()),
+        message_version: Some(common_utils::types::SemanticVersion::new(2, 2, 0)),
+        eci: Some("05".to_string()),
+        trans_status: Some(common_enums::TransactionStatus::Success),
+        acs_url: Some("https://acs.example.com/challenge".to_string()),
+        challenge_request: Some("creq".to_string()),
+        acs_reference_number: Some("acs_ref_1".to_string()),
+        acs_trans_id: Some("acs_trans_1".to_string()),
+        ..get_authentication()
+    };
+
+    let response = payments::AuthenticationRetrieveResponse::foreign_from(&authentication);
+    assert_eq!(response.authentication_connector, "netcetera");
+    assert_eq!(
+        response.cavv.map(ExposeInterface::expose),
+        Some("jJ81HADVRtXfCBATEp01CJUAAAA=".to_string())
+    );
+    assert_eq!(response.message_version, Some("2.2.0".to_string()));
+    assert_eq!(response.acs_url, authentication.acs_url);
+    assert_eq!(response.challenge_request, authentication.challenge_request);
+    assert_eq!(
+        response.trans_status,
+        Some(common_enums::TransactionStatus::Success)
+    );
+}
+
+#[test]
+fn test_authentication_retrieve_response_for_minimal_authentication() {
+    let response = payments::AuthenticationRetrieveResponse::foreign_from(&get_authentication());
+    assert_eq!(
+        response.status,
+        storage_enums::AuthenticationStatus::Started
+    );
+    assert_eq!(response.cavv, None);
+    assert_eq!(response.message_version, None);
+    assert_eq!(response.acs_url, None);
+}
+
+fn get_gsm_create_request(code: &str) -> gsm_api_types::GsmCreateRequest {
+    gsm_api_types::GsmCreateRequest {
+        connector: api_enums::Connector::Stripe,
+        flow: "Authorize".to_string(),
+        sub_flow: "sub_flow".to_string(),
+        code: code.to_string(),
+        message: "card_declined".to_string(),
+        status: "Failure".to_string(),
+        router_error: None,
+        decision: gsm_api_types::GsmDecision::Retry,
+        step_up_possible: false,
+        unified_code: None,
+        unified_message: None,
+    }
+}
+
+#[test]
+fn test_gsm_bulk_create_without_duplicates() {
+    let rows = vec![
+        get_gsm_create_request("E001"),
+        get_gsm_create_request("E002"),
+    ];
+
+    let gsm_rules = Vec::<storage::GatewayStatusMappingNew>::foreign_try_from(rows).unwrap();
+    assert_eq!(
+        gsm_rules
+            .iter()
+            .map(|gsm_rule| gsm_rule.code.as_str())
+            .collect::<Vec<_>>(),
+        vec!["E001", "E002"]
+    );
+    assert!(gsm_rules
+        .iter()
+        .all(|gsm_rule| gsm_rule.connector == "stripe"));
+}
+
+#[test]
+fn test_gsm_bulk_create_with_duplicate_key() {
+    let rows = vec![
+        get_gsm_create_request("E001"),
+        get_gsm_create_request("E002"),
+        get_gsm_create_request("E001"),
+    ];
+
+    let error = Vec::<storage::GatewayStatusMappingNew>::foreign_try_from(rows).unwrap_err();
+    assert!(matches!(
+        error.current_context(),
+        errors::ApiErrorResponse::PreconditionFailed { message } if message.contains("code: E001")
+    ));
+}
+
+fn get_gateway_status_map(decision: &str) -> storage::GatewayStatusMap {
+    let current_time = common_utils::date_time::now();
+    storage::GatewayStatusMap {
+        connector: "stripe".to_string(),
+        flow: "Authorize".to_string(),
+        sub_flow: "sub_flow".to_string(),
+        code: "E001".to_string(),
+        message: "card_declined".to_string(),
+        status: "Failure".to_string(),
+        router_error: None,
+        decision: decision.to_string(),
+        created_at: current_time,
+        last_modified: current_time,
+        step_up_possible: false,
+        unified_code: None,
+        unified_message: None,
+    }
+}
+
+#[test]
+fn test_gsm_response_decision_parsing() {
+    for (stored_decision, expected_decision) in [
+        ("retry", gsm_api_types::GsmDecision::Retry),
+        ("requeue", gsm_api_types::GsmDecision::Requeue),
+        ("do_default", gsm_api_types::GsmDecision::DoDefault),
+    ] {
+        let response =
+            gsm_api_types::GsmResponse::foreign_try_from(get_gateway_status_map(stored_decision))
+                .unwrap();
+        assert_eq!(response.decision, expected_decision);
+    }
+}
+
+#[test]
+fn test_gsm_response_with_malformed_decision() {
+    let error = gsm_api_types::GsmResponse::foreign_try_from(get_gateway_status_map("retry_later"))
+        .unwrap_err();
+    assert!(matches!(
+        error.current_context(),
+        errors::ApiErrorResponse::InternalServerError
+    ));
+}
+
+fn get_empty_address() -> domain::Address {
+    let current_time = common_utils::date_time::now();
+    domain::Address {
+        address_id: "add_1".to_string(),
+        city: None,
+        country: None,
+        line1: None,
+        line2: None,
+        line3: None,
+        state: None,
+        zip: None,
+        first_name: None,
+        last_name: None,
+        phone_number: None,
+        country_code: None,
+        created_at: current_time,
+        modified_at: current_time,
+        merchant_id: common_utils::id_type::MerchantId::default(),
+        updated_by: "postgres_only".to_string(),
+        email: None,
+    }
+}
+
+#[test]
+fn test_empty_address_is_collapsed_by_default() {
+    let api_address = api_types::Address::from(&get_empty_address());
+    assert_eq!(api_address.address, None);
+    assert_eq!(api_address.phone, None);
+    assert_eq!(api_address.email, None);
+}
+
+#[test]
+fn test_empty_address_is_preserved() {
+    let api_address = get_empty_address().to_api_address_preserving_empty();
+    assert_eq!(
+        api_address.address,
+        Some(api_types::AddressDetails::default())
+    );
+    assert_eq!(api_address.phone, None);
+    assert_eq!(api_address.email, None);
+}
+
+#[cfg(feature = "payouts")]
+#[test]
+fn test_payout_method_summary_for_card() {
+    use std::str::FromStr;
+
+    let payout_method_data =
+        api_models::payouts::PayoutMethodData::Card(api_models::payouts::CardPayout {
+            card_number: cards::CardNumber::from_str("4111111111111111").unwrap(),
+            expiry_month: Secret::new("10".to_string()),
+            expiry_year: Secret::new("2030".to_string()),
+            card_holder_name: None,
+        });
+
+    let summary = api_models::payouts::PayoutMethodSummary::foreign_from(&payout_method_data);
+    assert_eq!(
+        summary.payout_method_type,
+        api_enums::PaymentMethodType::Debit
+    );
+    assert_eq!(
+        summary.masked_identifier,
+        Some(api_models::payouts::PayoutMethodMaskedIdentifier::CardLast4("1111".to_string()))
+    );
+}
+
+#[cfg(feature = "payouts")]
+#[test]
+fn test_payout_method_summary_for_ach_bank_transfer() {
+    let payout_method_data = api_models::payouts::PayoutMethodData::Bank(
+        api_models::payouts::Bank::Ach(api_models::payouts::AchBankTransfer {
+            bank_account_number: Secret::new("000123456789".to_string()),
+            bank_routing_number: Secret::new("110000000".to_string()),
+            ..Default::default()
+        }),
+    );
+
+    let summary = api_models::payouts::PayoutMethodSummary::foreign_from(&payout_method_data);
+    assert_eq!(
+        summary.payout_method_type,
+        api_enums::PaymentMethodType::Ach
+    );
+    assert_eq!(
+        summary.masked_identifier,
+        Some(
+            api_models::payouts::PayoutMethodMaskedIdentifier::BankAccountLast4("6789".to_string())
+        )
+    );
+}
+
+#[cfg(feature = "payouts")]
+#[test]
+fn test_payout_method_summary_for_paypal_wallet() {
+    use std::str::FromStr;
+
+    let payout_method_data = api_models::payouts::PayoutMethodData::Wallet(
+        api_models::payouts::Wallet::Paypal(api_models::payouts::Paypal {
+            email: Some(pii::Email::from_str("john.doe@example.com").unwrap()),
+            telephone_number: None,
+            paypal_id: None,
+        }),
+    );
+
+    let summary = api_models::payouts::PayoutMethodSummary::foreign_from(&payout_method_data);
+    assert_eq!(
+        summary.payout_method_type,
+        api_enums::PaymentMethodType::Paypal
+    );
+    assert_eq!(
+        summary.masked_identifier,
+        Some(
+            api_models::payouts::PayoutMethodMaskedIdentifier::WalletEmail(
+                common_utils::new_type::MaskedEmail::from("john.doe@example.com".to_string())
+            )
+        )
+    );
+}
+
+#[test]
+fn test_incoming_webhook_event_to_intent_status() {
+    for (event, intent_status) in [
+        (
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentSuccess,
+            storage_enums::IntentStatus::Succeeded,
+        ),
+        (
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentFailure,
+            storage_enums::IntentStatus::Failed,
+        ),
+        (
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentProcessing,
+            storage_enums::IntentStatus::Processing,
+        ),
+        (
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentAuthorizationSuccess,
+            storage_enums::IntentStatus::RequiresCapture,
+        ),
+        (
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentAuthorizationFailure,
+            storage_enums::IntentStatus::Failed,
+        ),
+        (
+            api_models::webhooks::IncomingWebhookEvent::PaymentActionRequired,
+            storage_enums::IntentStatus::RequiresCustomerAction,
+        ),
+        (
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentCancelled,
+            storage_enums::IntentStatus::Cancelled,
+        ),
+    ] {
+        assert_eq!(
+            storage_enums::IntentStatus::foreign_try_from(event).unwrap(),
+            intent_status
+        );
+    }
+}
+
+#[test]
+fn test_unrelated_incoming_webhook_event_to_intent_status() {
+    assert!(storage_enums::IntentStatus::foreign_try_from(
+        api_models::webhooks::IncomingWebhookEvent::RefundSuccess
+    )
+    .is_err());
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+fn get_payment_method(
+    payment_method: storage_enums::PaymentMethod,
+    payment_method_type: storage_enums::PaymentMethodType,
+) -> domain::PaymentMethod {
+    let current_time = common_utils::date_time::now();
+    domain::PaymentMethod {
+        customer_id: common_utils::id_type::CustomerId::default(),
+        merchant_id: common_utils::id_type::MerchantId::default(),
+        payment_method_id: "pm_1".to_string(),
+        accepted_currency: None,
+        scheme: None,
+        token: None,
+        cardholder_name: None,
+        issuer_name: None,
+        issuer_country: None,
+        payer_country: None,
+        is_stored: None,
+        swift_code: None,
+        direct_debit_token: None,
+        created_at: current_time,
+        last_modified: current_time,
+        payment_method: Some(payment_method),
+        payment_method_type: Some(payment_method_type),
+        payment_method_issuer: None,
+        payment_method_issuer_code: None,
+        metadata: None,
+        payment_method_data: None,
+        locker_id: None,
+        last_used_at: current_time,
+        connector_mandate_details: None,
+        customer_acceptance: None,
+        status: storage_enums::PaymentMethodStatus::Active,
+        network_transaction_id: None,
+        client_secret: None,
+        payment_method_billing_address: None,
+        updated_by: None,
+        version: common_enums::ApiVersion::V1,
+        network_token_requestor_reference_id: None,
+        network_token_locker_id: None,
+        network_token_payment_method_data: None,
+    }
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[test]
+fn test_payment_method_response_last_used_at() {
+    let created_at = common_utils::date_time::now();
+    let last_used_at = created_at.saturating_add(time::Duration::days(30));
+    let payment_method = domain::PaymentMethod {
+        created_at,
+        last_modified: created_at,
+        last_used_at,
+        ..get_payment_method(
+            storage_enums::PaymentMethod::Card,
+            storage_enums::PaymentMethodType::Credit,
+        )
+    };
+
+    let response = payment_methods::PaymentMethodResponse::foreign_from((None, payment_method));
+    assert_eq!(response.last_used_at, Some(last_used_at));
+    assert_eq!(response.created, Some(created_at));
+}
+
+#[cfg(feature = "v1")]
+fn get_merchant_connector_account() -> domain::MerchantConnectorAccount {
+    let current_time = common_utils::date_time::now();
+    domain::MerchantConnectorAccount {
+        merchant_id: common_utils::id_type::MerchantId::default(),
+        connector_name: "stripe".to_string(),
+        connector_account_details: Encryptable::new(
+            Secret::new(serde_json::json!({
+                "auth_type": "HeaderKey",
+                "api_key": "sk_test_secret"
+            })),
+            Secret::new(Vec::new()),
+        ),
+        test_mode: Some(true),
+        disabled: Some(false),
+        merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId::wrap(
+            "mca_test".to_string(),
+        )
+        .unwrap(),
+        payment_methods_enabled: Some(vec![Secret::new(serde_json::json!({
+            "payment_method": "card",
+            "payment_method_types": [{
+                "payment_method_type": "credit",
+                "recurring_enabled": true,
+                "installment_payment_enabled": false
+            }]
+        }))]),
+        connector_type: storage_enums::ConnectorType::PaymentProcessor,
+        metadata: None,
+        frm_configs: None,
+        connector_label: Some("stripe_US_default".to_string()),
+        business_country: None,
+        business_label: None,
+        business_sub_label: None,
+        created_at: current_time,
+        modified_at: current_time,
+        connector_webhook_details: Some(Secret::new(serde_json::json!({
+            "merchant_secret": "whsec_secret"
+        }))),
+        profile_id: common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("pro_test"))
+            .unwrap(),
+        applepay_verified_domains: None,
+        pm_auth_config: None,
+        status: storage_enums::ConnectorStatus::Active,
+        connector_wallets_details: None,
+        additional_merchant_data: None,
+        version: common_enums::ApiVersion::V1,
+    }
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_merchant_connector_list_response_omits_secrets() {
+    let merchant_connector_account = get_merchant_connector_account();
+
+    let list_item = api_models::admin::MerchantConnectorListResponse::foreign_try_from(
+        merchant_connector_account,
+    )
+    .unwrap();
+    assert_eq!(
+        list_item.connector_label,
+        Some("stripe_US_default".to_string())
+    );
+    assert_eq!(list_item.status, storage_enums::ConnectorStatus::Active);
+    assert_eq!(
+        list_item.payment_methods_enabled.map(|pms| pms.len()),
+        Some(1)
+    );
+
+    let serialized_list_item = serde_json::to_value(list_item).unwrap();
+    assert!(serialized_list_item
+        .get("connector_account_details")
+        .is_none());
+    assert!(serialized_list_item
+        .get("connector_webhook_details")
+        .is_none());
+    assert!(!serialized_list_item.to_string().contains("sk_test_secret"));
+    assert!(!serialized_list_item.to_string().contains("whsec_secret"));
+}
+
+#[test]
+fn test_refund_event_details_for_full_refund() {
+    let refund_event_details = Option::<api_models::refunds::RefundEventDetails>::foreign_from((
+        storage_enums::RefundStatus::Success,
+        common_utils::types::MinorUnit::new(1000),
+        common_utils::types::MinorUnit::new(1000),
+    ));
+    assert_eq!(
+        refund_event_details,
+        Some(api_models::refunds::RefundEventDetails {
+            event_type: storage_enums::EventType::RefundSucceeded,
+            is_partial_refund: false,
+        })
+    );
+}
+
+#[test]
+fn test_refund_event_details_for_partial_refund() {
+    let refund_event_details = Option::<api_models::refunds::RefundEventDetails>::foreign_from((
+        storage_enums::RefundStatus::Success,
+        common_utils::types::MinorUnit::new(400),
+        common_utils::types::MinorUnit::new(1000),
+    ));
+    assert_eq!(
+        refund_event_details,
+        Some(api_models::refunds::RefundEventDetails {
+            event_type: storage_enums::EventType::RefundSucceeded,
+            is_partial_refund: true,
+        })
+    );
+}
+
+fn get_volume_splits(splits: &[u8]) -> Vec<api_models::routing::ConnectorVolumeSplit> {
+    splits
+        .iter()
+        .map(|split| api_models::routing::ConnectorVolumeSplit {
+            connector: routing_types::RoutableConnectorChoice {
+                choice_kind: api_models::routing::RoutableChoiceKind::FullStruct,
+                connector: api_enums::RoutableConnectors::Stripe,
+                merchant_connector_id: None,
+            },
+            split: *split,
+        })
+        .collect()
+}
+
+#[test]
+fn test_connector_selection_with_valid_volume_split() {
+    let routing_algorithm = routing_types::RoutingAlgorithm::foreign_try_from(
+        ConnectorSelection::VolumeSplit(get_volume_splits(&[60, 40])),
+    )
+    .unwrap();
+    assert!(matches!(
+        routing_algorithm,
+        routing_types::RoutingAlgorithm::VolumeSplit(splits) if splits.len() == 2
+    ));
+}
+
+#[test]
+fn test_connector_selection_with_invalid_volume_split() {
+    let error = routing_types::RoutingAlgorithm::foreign_try_from(ConnectorSelection::VolumeSplit(
+        get_volume_splits(&[60, 30]),
+    ))
+    .unwrap_err();
+    assert!(matches!(
+        error.current_context(),
+        common_utils::errors::ValidationError::InvalidValue { message } if message.contains("found 90")
+    ));
+}
+
+#[test]
+fn test_connector_selection_with_empty_volume_split() {
+    assert!(
+        routing_types::RoutingAlgorithm::foreign_try_from(ConnectorSelection::VolumeSplit(
+            Vec::new()
+        ))
+        .is_err()
+    );
+}
+
+#[test]
+fn test_capture_response_profile_and_merchant_connector_id() {
+    let profile_id =
+        common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("pro_test")).unwrap();
+    let merchant_connector_id =
+        common_utils::id_type::MerchantConnectorAccountId::wrap("mca_test".to_string()).unwrap();
+    let capture = storage::Capture {
+        profile_id: Some(profile_id.clone()),
+        merchant_connector_id: Some(merchant_connector_id.clone()),
+        ..get_capture(
+            1,
+            storage_enums::CaptureStatus::Charged,
+            1000,
+            storage_enums::Currency::USD,
+        )
+    };
+
+    let capture_response = payments::CaptureResponse::foreign_from(capture);
+    assert_eq!(capture_response.profile_id, Some(profile_id));
+    assert_eq!(
+        capture_response.merchant_connector_id,
+        Some(merchant_connector_id)
+    );
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+fn get_customer() -> domain::Customer {
+    let current_time = common_utils::date_time::now();
+    domain::Customer {
+        customer_id: common_utils::id_type::CustomerId::default(),
+        merchant_id: common_utils::id_type::MerchantId::default(),
+        name: None,
+        email: None,
+        phone: None,
+        phone_country_code: None,
+        description: None,
+        created_at: current_time,
+        metadata: None,
+        modified_at: current_time,
+        connector_customer: None,
+        address_id: None,
+        default_payment_method_id: None,
+        updated_by: None,
+        version: common_enums::ApiVersion::V1,
+    }
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[test]
+fn test_customer_data_export_with_all_details() {
+    let customer = domain::Customer {
+        name: Some(Encryptable::new(
+            Secret::new("John Doe".to_string()),
+            Secret::new(Vec::new()),
+        )),
+        email: Some(Encryptable::new(
+            Secret::new("john.doe@example.com".to_string()),
+            Secret::new(Vec::new()),
+        )),
+        phone: Some(Encryptable::new(
+            Secret::new("9123456789".to_string()),
+            Secret::new(Vec::new()),
+        )),
+        phone_country_code: Some("+1".to_string()),
+        metadata: Some(Secret::new(serde_json::json!({ "city": "NY" }))),
+        ..get_customer()
+    };
+
+    let export_details =
+        api_models::customers::CustomerDataExport::foreign_from(&customer).expose();
+    assert_eq!(export_details.customer_id, customer.customer_id);
+    assert_eq!(export_details.name, Some("John Doe".to_string()));
+    assert_eq!(
+        export_details.email,
+        Some("john.doe@example.com".to_string())
+    );
+    assert_eq!(export_details.phone, Some("9123456789".to_string()));
+    assert_eq!(export_details.phone_country_code, Some("+1".to_string()));
+    assert_eq!(export_details.created_at, customer.created_at);
+    assert_eq!(
+        export_details.metadata,
+        Some(serde_json::json!({ "city": "NY" }))
+    );
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[test]
+fn test_customer_data_export_without_details() {
+    let export_details =
+        api_models::customers::CustomerDataExport::foreign_from(&get_customer()).expose();
+    assert_eq!(export_details.name, None);
+    assert_eq!(export_details.email, None);
+    assert_eq!(export_details.phone, None);
+    assert_eq!(export_details.phone_country_code, None);
+    assert_eq!(export_details.metadata, None);
+}
+
+#[test]
+fn test_attempt_status_display_label() {
+    for (attempt_status, label) in [
+        (storage_enums::AttemptStatus::Charged, "Captured"),
+        (
+            storage_enums::AttemptStatus::PartialChargedAndChargeable,
+            "Partially captured, more capturable",
+        ),
+        (
+            storage_enums::AttemptStatus::PaymentMethodAwaited,
+            "Awaiting payment method",
+        ),
+    ] {
+        let attempt_status_display = payments::AttemptStatusDisplay::foreign_from(attempt_status);
+        assert_eq!(attempt_status_display.status, attempt_status);
+        assert_eq!(attempt_status_display.label, label);
+    }
+}
+
+#[test]
+fn test_capture_status_to_attempt_status() {
+    for (capture_status, attempt_status) in [
+        (
+            storage_enums::CaptureStatus::Charged,
+            storage_enums::AttemptStatus::Charged,
+        ),
+        (
+            storage_enums::CaptureStatus::Pending,
+            storage_enums::AttemptStatus::CaptureInitiated,
+        ),
+        (
+            storage_enums::CaptureStatus::Failed,
+            storage_enums::AttemptStatus::CaptureFailed,
+        ),
+    ] {
+        assert_eq!(
+            storage_enums::AttemptStatus::foreign_from(capture_status),
+            attempt_status
+        );
+    }
+}
+
+fn get_file_metadata(available: bool) -> storage::FileMetadata {
+    storage::FileMetadata {
+        file_id: "file_1".to_string(),
+        merchant_id: common_utils::id_type::MerchantId::default(),
+        file_name: Some("evidence.pdf".to_string()),
+        file_size: 1024,
+        file_type: "application/pdf".to_string(),
+        provider_file_id: available.then(|| "provider_file_1".to_string()),
+        file_upload_provider: Some(common_enums::FileUploadProvider::Router),
+        available,
+        created_at: common_utils::date_time::now(),
+        connector_label: None,
+        profile_id: None,
+        merchant_connector_id: None,
+    }
+}
+
+#[test]
+fn test_file_download_descriptor_for_available_file() {
+    let descriptor =
+        api_models::files::FileDownloadDescriptor::foreign_from(get_file_metadata(true));
+    assert_eq!(descriptor.file_id, "file_1");
+    assert_eq!(
+        descriptor.provider_file_id,
+        Some("provider_file_1".to_string())
+    );
+    assert_eq!(descriptor.file_type, "application/pdf");
+    assert_eq!(descriptor.not_available_reason, None);
+}
+
+#[test]
+fn test_file_download_descriptor_for_unavailable_file() {
+    let descriptor =
+        api_models::files::FileDownloadDescriptor::foreign_from(get_file_metadata(false));
+    assert_eq!(descriptor.provider_file_id, None);
+    assert!(descriptor.not_available_reason.is_some());
+}
+
+#[test]
+fn test_mandate_amount_data_round_trip() {
+    for amount in [0, 6540, i64::MAX] {
+        let mandate_amount_data = payments::MandateAmountData {
+            amount: common_utils::types::MinorUnit::new(amount),
+            currency: api_enums::Currency::USD,
+            start_date: Some(common_utils::date_time::now()),
+            end_date: None,
+            metadata: Some(Secret::new(serde_json::json!({ "frequency": "DAILY" }))),
+        };
+
+        let storage_mandate_amount_data =
+            storage_enums::MandateAmountData::foreign_try_from(mandate_amount_data.clone())
+                .unwrap();
+        assert_eq!(
+            storage_mandate_amount_data.amount,
+            common_utils::types::MinorUnit::new(amount)
+        );
+        assert_eq!(
+            payments::MandateAmountData::foreign_from(storage_mandate_amount_data),
+            mandate_amount_data
+        );
+    }
+}
+
+fn get_authorization(
+    authorization_id: &str,
+    amount: i64,
+    previously_authorized_amount: i64,
+    status: storage_enums::AuthorizationStatus,
+    created_at: time::PrimitiveDateTime,
+) -> storage::Authorization {
+    storage::Authorization {
+        authorization_id: authorization_id.to_string(),
+        merchant_id: common_utils::id_type::MerchantId::default(),
+        payment_id: common_utils::id_type::PaymentId::default(),
+        amount: common_utils::types::MinorUnit::new(amount),
+        created_at,
+        modified_at: created_at,
+        status,
+        error_code: None,
+        error_message: None,
+        connector_authorization_id: None,
+        previously_authorized_amount: common_utils::types::MinorUnit::new(
+            previously_authorized_amount,
+        ),
+    }
+}
+
+#[test]
+fn test_incremental_authorization_history_without_authorizations() {
+    let history = payments::IncrementalAuthorizationHistory::foreign_from(Vec::new());
+    assert!(history.authorizations.is_empty());
+    assert_eq!(history.total_authorized_amount, None);
+    assert_eq!(history.successful_authorizations_count, 0);
+}
+
+#[test]
+fn test_incremental_authorization_history_with_one_authorization() {
+    let history = payments::IncrementalAuthorizationHistory::foreign_from(vec![get_authorization(
+        "auth_1",
+        1500,
+        1000,
+        storage_enums::AuthorizationStatus::Success,
+        common_utils::date_time::now(),
+    )]);
+    assert_eq!(history.authorizations.len(), 1);
+    assert_eq!(
+        history.total_authorized_amount,
+        Some(common_utils::types::MinorUnit::new(1500))
+    );
+    assert_eq!(history.successful_authorizations_count, 1);
+}
+
+#[test]
+fn test_incremental_authorization_history_with_failed_authorization() {
+    let created_at = common_utils::date_time::now();
+    let history = payments::IncrementalAuthorizationHistory::foreign_from(vec![
+        get_authorization(
+            "auth_3",
+            3000,
+            2000,
+            storage_enums::AuthorizationStatus::Failure,
+            created_at.saturating_add(time::Duration::minutes(2)),
+        ),
+        get_authorization(
+            "auth_1",
+            1500,
+            1000,
+            storage_enums::AuthorizationStatus::Success,
+            created_at,
+        ),
+        get_authorization(
+            "auth_2",
+            2000,
+            1500,
+            storage_enums::AuthorizationStatus::Success,
+            created_at.saturating_add(time::Duration::minutes(1)),
+        ),
+    ]);
+    assert_eq!(
+        history
+            .authorizations
+            .iter()
+            .map(|authorization| authorization.authorization_id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["auth_1", "auth_2", "auth_3"]
+    );
+    assert_eq!(
+        history.total_authorized_amount,
+        Some(common_utils::types::MinorUnit::new(2000))
+    );
+    assert_eq!(history.successful_authorizations_count, 2);
+}
+
+#[test]
+fn test_organization_new_metadata() {
+    let metadata = Secret::new(serde_json::json!({
+        "support_email": "support@example.com",
+        "default_currency": "USD"
+    }));
+    let org_new = api_models::organization::OrganizationNew {
+        metadata: Some(metadata.clone()),
+        ..api_models::organization::OrganizationNew::new(Some("org_name".to_string()))
+    };
+
+    let org_new_db = diesel_models::organization::OrganizationNew::foreign_from(org_new);
+    assert_eq!(org_new_db.metadata, Some(metadata));
+
+    let org_new_db = diesel_models::organization::OrganizationNew::foreign_from(
+        api_models::organization::OrganizationNew::new(None),
+    );
+    assert_eq!(org_new_db.metadata, None);
+}
+
+#[test]
+fn test_fingerprint_input_for_card() {
+    use std::str::FromStr;
+
+    let card_number = cards::CardNumber::from_str("4111111111111111").unwrap();
+    let payment_method_data = payments::PaymentMethodData::Card(payments::Card {
+        card_number: card_number.clone(),
+        card_exp_month: Secret::new("10".to_string()),
+        card_exp_year: Secret::new("2030".to_string()),
+        card_holder_name: None,
+        card_cvc: Secret::new("123".to_string()),
+        card_issuer: None,
+        card_network: None,
+        card_type: None,
+        card_issuing_country: None,
+        bank_code: None,
+        nick_name: None,
+    });
+
+    let fingerprint_input =
+        crate::types::FingerprintInput::foreign_try_from(&payment_method_data).unwrap();
+    assert!(matches!(
+        fingerprint_input,
+        crate::types::FingerprintInput::Card(number) if number == card_number
+    ));
+}
+
+#[test]
+fn test_fingerprint_input_for_sepa_bank_debit() {
+    let payment_method_data =
+        payments::PaymentMethodData::BankDebit(payments::BankDebitData::SepaBankDebit {
+            billing_details: None,
+            iban: Secret::new("DE89370400440532013000".to_string()),
+            bank_account_holder_name: None,
+        });
+
+    let fingerprint_input =
+        crate::types::FingerprintInput::foreign_try_from(&payment_method_data).unwrap();
+    assert!(matches!(
+        fingerprint_input,
+        crate::types::FingerprintInput::Iban(iban) if iban.peek() == "DE89370400440532013000"
+    ));
+}
+
+#[test]
+fn test_fingerprint_input_without_fingerprintable_data() {
+    for payment_method_data in [
+        payments::PaymentMethodData::MandatePayment,
+        payments::PaymentMethodData::Reward,
+        payments::PaymentMethodData::CardToken(payments::CardToken {
+            card_holder_name: None,
+            card_cvc: Some(Secret::new("123".to_string())),
+        }),
+    ] {
+        let error =
+            crate::types::FingerprintInput::foreign_try_from(&payment_method_data).unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { .. }
+        ));
+    }
+}
+
+fn get_api_key(expires_at: Option<time::PrimitiveDateTime>) -> diesel_models::api_keys::ApiKey {
+    diesel_models::api_keys::ApiKey {
+        key_id: common_utils::id_type::ApiKeyId::try_from(std::borrow::Cow::from("dev_test"))
+            .unwrap(),
+        merchant_id: common_utils::id_type::MerchantId::default(),
+        name: "test key".to_string(),
+        description: None,
+        hashed_api_key: "hashed_key".to_string().into(),
+        prefix: "dev_abc".to_string(),
+        created_at: common_utils::date_time::now(),
+        expires_at,
+        last_used: None,
+    }
+}
+
+#[test]
+fn test_api_key_response_expiry() {
+    let now = common_utils::date_time::now();
+    for (expires_at, expected_is_expired) in [
+        (Some(now + time::Duration::days(1)), false),
+        (Some(now - time::Duration::days(1)), true),
+        (None, false),
+    ] {
+        let response =
+            api_models::api_keys::RetrieveApiKeyResponse::foreign_from(get_api_key(expires_at));
+        assert_eq!(response.is_expired, expected_is_expired);
+    }
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_attempt_error_details_for_errored_attempt() {
+    let payment_attempt = storage::PaymentAttempt {
+        error_code: Some("card_declined".to_string()),
+        error_reason: Some("Insufficient funds".to_string()),
+        unified_code: Some("UE_9000".to_string()),
+        unified_message: Some("Something went wrong".to_string()),
+        ..get_payment_attempt(storage_enums::AttemptStatus::Failure, None)
+    };
+
+    let error_details =
+        Option::<payments::AttemptErrorDetails>::foreign_from(&payment_attempt).unwrap();
+    assert_eq!(error_details.code.as_deref(), Some("card_declined"));
+    assert_eq!(error_details.message.as_deref(), Some("Insufficient funds"));
+    assert_eq!(error_details.unified_code.as_deref(), Some("UE_9000"));
+    assert_eq!(
+        error_details.unified_message.as_deref(),
+        Some("Something went wrong")
+    );
+
+    let response = payments::PaymentAttemptResponse::foreign_from(payment_attempt);
+    assert_eq!(response.error_code.as_deref(), Some("card_declined"));
+    assert_eq!(response.error_details, Some(error_details));
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_attempt_error_details_for_clean_attempt() {
+    let payment_attempt = get_payment_attempt(storage_enums::AttemptStatus::Charged, None);
+
+    assert_eq!(
+        Option::<payments::AttemptErrorDetails>::foreign_from(&payment_attempt),
+        None
+    );
+}
+
+#[test]
+fn test_payment_method_type_to_payment_method_is_exhaustive() {
+    use strum::IntoEnumIterator;
+
+    let expected_payment_methods = [
+        (
+            api_enums::PaymentMethodType::ApplePay,
+            api_enums::PaymentMethod::Wallet,
+        ),
+        (
+            api_enums::PaymentMethodType::GooglePay,
+            api_enums::PaymentMethod::Wallet,
+        ),
+        (
+            api_enums::PaymentMethodType::Paypal,
+            api_enums::PaymentMethod::Wallet,
+        ),
+        (
+            api_enums::PaymentMethodType::AliPay,
+            api_enums::PaymentMethod::Wallet,
+        ),
+        (
+            api_enums::PaymentMethodType::AliPayHk,
+            api_enums::PaymentMethod::Wallet,
+        ),
+        (
+            api_enums::PaymentMethodType::Dana,
+            api_enums::PaymentMethod::Wallet,
+        ),
+        (
+            api_enums::PaymentMethodType::MbWay,
+            api_enums::PaymentMethod::Wallet,
+        ),
+        (
+            api_enums::PaymentMethodType::MobilePay,
+            api_enums::PaymentMethod::Wallet,
+        ),
+        (
+            api_enums::PaymentMethodType::Paze,
+            api_enums::PaymentMethod::Wallet,
+        ),
+        (
+            api_enums::PaymentMethodType::SamsungPay,
+            api_enums::PaymentMethod::Wallet,
+        ),
+        (
+            api_enums::PaymentMethodType::Twint,
+            api_enums::PaymentMethod::Wallet,
+        ),
+        (
+            api_enums::PaymentMethodType::Vipps,
+            api_enums::PaymentMethod::Wallet,
+        ),
+        (
+            api_enums::PaymentMethodType::TouchNGo,
+            api_enums::PaymentMethod::Wallet,
+        ),
+        (
+            api_enums::PaymentMethodType::Swish,
+            api_enums::PaymentMethod::Wallet,
+        ),
+        (
+            api_enums::PaymentMethodType::WeChatPay,
+            api_enums::PaymentMethod::Wallet,
+        ),
+        (
+            api_enums::PaymentMethodType::GoPay,
+            api_enums::PaymentMethod::Wallet,
+        ),
+        (
+            api_enums::PaymentMethodType::Gcash,
+            api_enums::PaymentMethod::Wallet,
+        ),
+        (
+            api_enums::PaymentMethodType::Momo,
+            api_enums::PaymentMethod::Wallet,
+        ),
+        (
+            api_enums::PaymentMethodType::Cashapp,
+            api_enums::PaymentMethod::Wallet,
+        ),
+        (
+            api_enums::PaymentMethodType::KakaoPay,
+            api_enums::PaymentMethod::Wallet,
+        ),
+        (
+            api_enums::PaymentMethodType::Venmo,
+            api_enums::PaymentMethod::Wallet,
+        ),
+        (
+            api_enums::PaymentMethodType::Mifinity,
+            api_enums::PaymentMethod::Wallet,
+        ),
+        (
+            api_enums::PaymentMethodType::Affirm,
+            api_enums::PaymentMethod::PayLater,
+        ),
+        (
+            api_enums::PaymentMethodType::Alma,
+            api_enums::PaymentMethod::PayLater,
+        ),
+        (
+            api_enums::PaymentMethodType::AfterpayClearpay,
+            api_enums::PaymentMethod::PayLater,
+        ),
+        (
+            api_enums::PaymentMethodType::Klarna,
+            api_enums::PaymentMethod::PayLater,
+        ),
+        (
+            api_enums::PaymentMethodType::PayBright,
+            api_enums::PaymentMethod::PayLater,
+        ),
+        (
+            api_enums::PaymentMethodType::Atome,
+            api_enums::PaymentMethod::PayLater,
+        ),
+        (
+            api_enums::PaymentMethodType::Walley,
+            api_enums::PaymentMethod::PayLater,
+        ),
+        (
+            api_enums::PaymentMethodType::Giropay,
+            api_enums::PaymentMethod::BankRedirect,
+        ),
+        (
+            api_enums::PaymentMethodType::Ideal,
+            api_enums::PaymentMethod::BankRedirect,
+        ),
+        (
+            api_enums::PaymentMethodType::Sofort,
+            api_enums::PaymentMethod::BankRedirect,
+        ),
+        (
+            api_enums::PaymentMethodType::Eps,
+            api_enums::PaymentMethod::BankRedirect,
+        ),
+        (
+            api_enums::PaymentMethodType::BancontactCard,
+            api_enums::PaymentMethod::BankRedirect,
+        ),
+        (
+            api_enums::PaymentMethodType::Blik,
+            api_enums::PaymentMethod::BankRedirect,
+        ),
+        (
+            api_enums::PaymentMethodType::LocalBankRedirect,
+            api_enums::PaymentMethod::BankRedirect,
+        ),
+        (
+            api_enums::PaymentMethodType::OnlineBankingThailand,
+            api_enums::PaymentMethod::BankRedirect,
+        ),
+        (
+            api_enums::PaymentMethodType::OnlineBankingCzechRepublic,
+            api_enums::PaymentMethod::BankRedirect,
+        ),
+        (
+            api_enums::PaymentMethodType::OnlineBankingFinland,
+            api_enums::PaymentMethod::BankRedirect,
+        ),
+        (
+            api_enums::PaymentMethodType::OnlineBankingFpx,
+            api_enums::PaymentMethod::BankRedirect,
+        ),
+        (
+            api_enums::PaymentMethodType::OnlineBankingPoland,
+            api_enums::PaymentMethod::BankRedirect,
+        ),
+        (
+            api_enums::PaymentMethodType::OnlineBankingSlovakia,
+            api_enums::PaymentMethod::BankRedirect,
+        ),
+        (
+            api_enums::PaymentMethodType::OpenBankingUk,
+            api_enums::PaymentMethod::BankRedirect,
+        ),
+        (
+            api_enums::PaymentMethodType::Przelewy24,
+            api_enums::PaymentMethod::BankRedirect,
+        ),
+        (
+            api_enums::PaymentMethodType::Trustly,
+            api_enums::PaymentMethod::BankRedirect,
+        ),
+        (
+            api_enums::PaymentMethodType::Bizum,
+            api_enums::PaymentMethod::BankRedirect,
+        ),
+        (
+            api_enums::PaymentMethodType::Interac,
+            api_enums::PaymentMethod::BankRedirect,
+        ),
+        (
+            api_enums::PaymentMethodType::UpiCollect,
+            api_enums::PaymentMethod::Upi,
+        ),
+        (
+            api_enums::PaymentMethodType::UpiIntent,
+            api_enums::PaymentMethod::Upi,
+        ),
+        (
+            api_enums::PaymentMethodType::CryptoCurrency,
+            api_enums::PaymentMethod::Crypto,
+        ),
+        (
+            api_enums::PaymentMethodType::Ach,
+            api_enums::PaymentMethod::BankDebit,
+        ),
+        (
+            api_enums::PaymentMethodType::Sepa,
+            api_enums::PaymentMethod::BankDebit,
+        ),
+        (
+            api_enums::PaymentMethodType::Bacs,
+            api_enums::PaymentMethod::BankDebit,
+        ),
+        (
+            api_enums::PaymentMethodType::Becs,
+            api_enums::PaymentMethod::BankDebit,
+        ),
+        (
+            api_enums::PaymentMethodType::Credit,
+            api_enums::PaymentMethod::Card,
+        ),
+        (
+            api_enums::PaymentMethodType::Debit,
+            api_enums::PaymentMethod::Card,
+        ),
+        (
+            api_enums::PaymentMethodType::Evoucher,
+            api_enums::PaymentMethod::Reward,
+        ),
+        (
+            api_enums::PaymentMethodType::ClassicReward,
+            api_enums::PaymentMethod::Reward,
+        ),
+        (
+            api_enums::PaymentMethodType::Boleto,
+            api_enums::PaymentMethod::Voucher,
+        ),
+        (
+            api_enums::PaymentMethodType::Efecty,
+            api_enums::PaymentMethod::Voucher,
+        ),
+        (
+            api_enums::PaymentMethodType::PagoEfectivo,
+            api_enums::PaymentMethod::Voucher,
+        ),
+        (
+            api_enums::PaymentMethodType::RedCompra,
+            api_enums::PaymentMethod::Voucher,
+        ),
+        (
+            api_enums::PaymentMethodType::Alfamart,
+            api_enums::PaymentMethod::Voucher,
+        ),
+        (
+            api_enums::PaymentMethodType::Indomaret,
+            api_enums::PaymentMethod::Voucher,
+        ),
+        (
+            api_enums::PaymentMethodType::Oxxo,
+            api_enums::PaymentMethod::Voucher,
+        ),
+        (
+            api_enums::PaymentMethodType::SevenEleven,
+            api_enums::PaymentMethod::Voucher,
+        ),
+        (
+            api_enums::PaymentMethodType::Lawson,
+            api_enums::PaymentMethod::Voucher,
+        ),
+        (
+            api_enums::PaymentMethodType::MiniStop,
+            api_enums::PaymentMethod::Voucher,
+        ),
+        (
+            api_enums::PaymentMethodType::FamilyMart,
+            api_enums::PaymentMethod::Voucher,
+        ),
+        (
+            api_enums::PaymentMethodType::Seicomart,
+            api_enums::PaymentMethod::Voucher,
+        ),
+        (
+            api_enums::PaymentMethodType::PayEasy,
+            api_enums::PaymentMethod::Voucher,
+        ),
+        (
+            api_enums::PaymentMethodType::RedPagos,
+            api_enums::PaymentMethod::Voucher,
+        ),
+        (
+            api_enums::PaymentMethodType::Pse,
+            api_enums::PaymentMethod::BankTransfer,
+        ),
+        (
+            api_enums::PaymentMethodType::Multibanco,
+            api_enums::PaymentMethod::BankTransfer,
+        ),
+        (
+            api_enums::PaymentMethodType::PermataBankTransfer,
+            api_enums::PaymentMethod::BankTransfer,
+        ),
+        (
+            api_enums::PaymentMethodType::BcaBankTransfer,
+            api_enums::PaymentMethod::BankTransfer,
+        ),
+        (
+            api_enums::PaymentMethodType::BniVa,
+            api_enums::PaymentMethod::BankTransfer,
+        ),
+        (
+            api_enums::PaymentMethodType::BriVa,
+            api_enums::PaymentMethod::BankTransfer,
+        ),
+        (
+            api_enums::PaymentMethodType::CimbVa,
+            api_enums::PaymentMethod::BankTransfer,
+        ),
+        (
+            api_enums::PaymentMethodType::DanamonVa,
+            api_enums::PaymentMethod::BankTransfer,
+        ),
+        (
+            api_enums::PaymentMethodType::MandiriVa,
+            api_enums::PaymentMethod::BankTransfer,
+        ),
+        (
+            api_enums::PaymentMethodType::LocalBankTransfer,
+            api_enums::PaymentMethod::BankTransfer,
+        ),
+        (
+            api_enums::PaymentMethodType::Pix,
+            api_enums::PaymentMethod::BankTransfer,
+        ),
+        (
+            api_enums::PaymentMethodType::Givex,
+            api_enums::PaymentMethod::GiftCard,
+        ),
+        (
+            api_enums::PaymentMethodType::PaySafeCard,
+            api_enums::PaymentMethod::GiftCard,
+        ),
+        (
+            api_enums::PaymentMethodType::Benefit,
+            api_enums::PaymentMethod::CardRedirect,
+        ),
+        (
+            api_enums::PaymentMethodType::Knet,
+            api_enums::PaymentMethod::CardRedirect,
+        ),
+        (
+            api_enums::PaymentMethodType::MomoAtm,
+            api_enums::PaymentMethod::CardRedirect,
+        ),
+        (
+            api_enums::PaymentMethodType::CardRedirect,
+            api_enums::PaymentMethod::CardRedirect,
+        ),
+        (
+            api_enums::PaymentMethodType::Fps,
+            api_enums::PaymentMethod::RealTimePayment,
+        ),
+        (
+            api_enums::PaymentMethodType::DuitNow,
+            api_enums::PaymentMethod::RealTimePayment,
+        ),
+        (
+            api_enums::PaymentMethodType::PromptPay,
+            api_enums::PaymentMethod::RealTimePayment,
+        ),
+        (
+            api_enums::PaymentMethodType::VietQr,
+            api_enums::PaymentMethod::RealTimePayment,
+        ),
+        (
+            api_enums::PaymentMethodType::OpenBankingPIS,
+            api_enums::PaymentMethod::OpenBanking,
+        ),
+    ];
+
+    assert_eq!(
+        expected_payment_methods.len(),
+        api_enums::PaymentMethodType::iter().count(),
+        "every payment method type must have an expected payment method"
+    );
+    for payment_method_type in api_enums::PaymentMethodType::iter() {
+        let (_, expected_payment_method) = expected_payment_methods
+            .iter()
+            .find(|(pmt, _)| *pmt == payment_method_type)
+            .unwrap();
+        assert_eq!(
+            api_enums::PaymentMethod::foreign_from(payment_method_type),
+            *expected_payment_method,
+            "unexpected payment method for {payment_method_type}"
+        );
+    }
+}
+
+fn get_payment_link(fulfilment_time: Option<time::PrimitiveDateTime>) -> storage::PaymentLink {
+    let current_time = common_utils::date_time::now();
+    storage::PaymentLink {
+        payment_link_id: "plink_test".to_string(),
+        payment_id: common_utils::id_type::PaymentId::default(),
+        link_to_pay: "https://example.com/payment_link/plink_test".to_string(),
+        merchant_id: common_utils::id_type::MerchantId::default(),
+        amount: common_utils::types::MinorUnit::new(6540),
+        currency: Some(storage_enums::Currency::USD),
+        created_at: current_time,
+        last_modified_at: current_time,
+        fulfilment_time,
+        custom_merchant_name: None,
+        payment_link_config: None,
+        description: None,
+        profile_id: None,
+        secure_link: None,
+    }
+}
+
+#[test]
+fn test_payment_link_response_seconds_until_expiry() {
+    let response = payments::RetrievePaymentLinkResponse::foreign_from((
+        get_payment_link(Some(
+            common_utils::date_time::now() + time::Duration::minutes(15),
+        )),
+        payments::PaymentLinkStatus::Active,
+    ));
+    let seconds_until_expiry = response.seconds_until_expiry.unwrap();
+    assert!(seconds_until_expiry > 0 && seconds_until_expiry <= 900);
+
+    let response = payments::RetrievePaymentLinkResponse::foreign_from((
+        get_payment_link(Some(
+            common_utils::date_time::now() - time::Duration::minutes(15),
+        )),
+        payments::PaymentLinkStatus::Expired,
+    ));
+    assert_eq!(response.seconds_until_expiry, Some(0));
+
+    let response = payments::RetrievePaymentLinkResponse::foreign_from((
+        get_payment_link(None),
+        payments::PaymentLinkStatus::Active,
+    ));
+    assert_eq!(response.seconds_until_expiry, None);
+}
+
+#[test]
+fn test_connector_from_connector_name() {
+    assert_eq!(
+        api_enums::Connector::foreign_try_from("stripe").unwrap(),
+        api_enums::Connector::Stripe
+    );
+    assert_eq!(
+        api_enums::Connector::foreign_try_from("Stripe").unwrap(),
+        api_enums::Connector::Stripe
+    );
+
+    let error = api_enums::Connector::foreign_try_from("not_a_connector").unwrap_err();
+    assert!(matches!(
+        error.current_context(),
+        common_utils::errors::ValidationError::InvalidValue { message }
+            if message == "not_a_connector is not a known connector"
+    ));
+}
+
+#[test]
+fn test_address_with_shipping_fallback_to_billing() {
+    let shipping_address = domain::Address {
+        city: Some("Amsterdam".to_string()),
+        ..get_empty_address()
+    };
+    let billing_address = domain::Address {
+        city: Some("Berlin".to_string()),
+        ..get_empty_address()
+    };
+
+    let address =
+        api_types::Address::foreign_from((Some(&shipping_address), Some(&billing_address)));
+    assert_eq!(
+        address.address.and_then(|details| details.city).as_deref(),
+        Some("Amsterdam")
+    );
+
+    let address = api_types::Address::foreign_from((None, Some(&billing_address)));
+    assert_eq!(
+        address.address.and_then(|details| details.city).as_deref(),
+        Some("Berlin")
+    );
+
+    let address = api_types::Address::foreign_from((None, None));
+    assert_eq!(address, api_types::Address::default());
+}
+
+#[cfg(feature = "olap")]
+fn get_event(event_id: &str, initial_attempt_id: &str) -> domain::Event {
+    domain::Event {
+        event_id: event_id.to_string(),
+        event_type: storage_enums::EventType::PaymentSucceeded,
+        event_class: storage_enums::EventClass::Payments,
+        is_webhook_notified: true,
+        primary_object_id: "pay_test".to_string(),
+        primary_object_type: storage_enums::EventObjectType::PaymentDetails,
+        created_at: common_utils::date_time::now(),
+        merchant_id: Some(common_utils::id_type::MerchantId::default()),
+        business_profile_id: Some(
+            common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("pro_test")).unwrap(),
+        ),
+        primary_object_created_at: None,
+        idempotent_event_id: None,
+        initial_attempt_id: Some(initial_attempt_id.to_string()),
+        request: Some(Encryptable::new(
+            Secret::new(r#"{"body":"{}","headers":[]}"#.to_string()),
+            Secret::new(Vec::new()),
+        )),
+        response: Some(Encryptable::new(
+            Secret::new(r#"{"body":null,"headers":null,"status_code":200}"#.to_string()),
+            Secret::new(Vec::new()),
+        )),
+        delivery_attempt: Some(storage_enums::WebhookDeliveryAttempt::InitialAttempt),
+        metadata: None,
+    }
+}
+
+#[cfg(feature = "olap")]
+#[test]
+fn test_event_retrieve_response_total_delivery_attempts_for_single_attempt() {
+    let responses =
+        Vec::<api_models::webhook_events::EventRetrieveResponse>::foreign_try_from(vec![
+            get_event("evt_1", "evt_1"),
+        ])
+        .unwrap();
+    let response = responses.first().unwrap();
+    assert_eq!(response.total_delivery_attempts, Some(1));
+    assert_eq!(
+        response.delivery_attempt,
+        Some(storage_enums::WebhookDeliveryAttempt::InitialAttempt)
+    );
+}
+
+#[cfg(feature = "olap")]
+#[test]
+fn test_event_retrieve_response_total_delivery_attempts_for_attempt_chain() {
+    let retried_event = domain::Event {
+        delivery_attempt: Some(storage_enums::WebhookDeliveryAttempt::ManualRetry),
+        ..get_event("evt_2", "evt_1")
+    };
+
+    let responses =
+        Vec::<api_models::webhook_events::EventRetrieveResponse>::foreign_try_from(vec![
+            get_event("evt_1", "evt_1"),
+            retried_event,
+            get_event("evt_3", "evt_1"),
+        ])
+        .unwrap();
+    assert!(responses
+        .iter()
+        .all(|response| response.total_delivery_attempts == Some(3)));
+    assert_eq!(
+        responses.get(1).unwrap().delivery_attempt,
+        Some(storage_enums::WebhookDeliveryAttempt::ManualRetry)
+    );
+}
+
+#[cfg(feature = "payouts")]
+#[test]
+fn test_incoming_webhook_event_to_payout_status() {
+    for (event, payout_status) in [
+        (
+            api_models::webhooks::IncomingWebhookEvent::PayoutSuccess,
+            storage_enums::PayoutStatus::Success,
+        ),
+        (
+            api_models::webhooks::IncomingWebhookEvent::PayoutFailure,
+            storage_enums::PayoutStatus::Failed,
+        ),
+        (
+            api_models::webhooks::IncomingWebhookEvent::PayoutCancelled,
+            storage_enums::PayoutStatus::Cancelled,
+        ),
+        (
+            api_models::webhooks::IncomingWebhookEvent::PayoutProcessing,
+            storage_enums::PayoutStatus::Pending,
+        ),
+        (
+            api_models::webhooks::IncomingWebhookEvent::PayoutCreated,
+            storage_enums::PayoutStatus::Initiated,
+        ),
+        (
+            api_models::webhooks::IncomingWebhookEvent::PayoutExpired,
+            storage_enums::PayoutStatus::Expired,
+        ),
+        (
+            api_models::webhooks::IncomingWebhookEvent::PayoutReversed,
+            storage_enums::PayoutStatus::Reversed,
+        ),
+    ] {
+        assert_eq!(
+            storage_enums::PayoutStatus::foreign_try_from(event).unwrap(),
+            payout_status
+        );
+    }
+}
+
+#[cfg(feature = "payouts")]
+#[test]
+fn test_unrelated_incoming_webhook_event_to_payout_status() {
+    assert!(matches!(
+        storage_enums::PayoutStatus::foreign_try_from(
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentSuccess
+        ),
+        Err(errors::ValidationError::IncorrectValueProvided {
+            field_name: "incoming_webhook_event_type"
+        })
+    ));
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_dispute_to_event_type_and_dispute_response() {
+    let dispute = storage::Dispute {
+        dispute_status: storage_enums::DisputeStatus::DisputeWon,
+        ..get_dispute(serde_json::json!({}))
+    };
+
+    let (event_type, dispute_response) = <(
+        storage_enums::EventType,
+        api_models::disputes::DisputeResponse,
+    )>::foreign_from((dispute.clone(), None));
+    assert_eq!(event_type, storage_enums::EventType::DisputeWon);
+    assert_eq!(dispute_response.dispute_id, dispute.dispute_id);
+    assert_eq!(dispute_response.payment_id, dispute.payment_id);
+    assert_eq!(dispute_response.attempt_id, dispute.attempt_id);
+    assert_eq!(
+        dispute_response.dispute_status,
+        storage_enums::DisputeStatus::DisputeWon
+    );
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_merchant_connector_list_response_carries_metadata() {
+    let metadata = serde_json::json!({ "environment": "staging" });
+    let merchant_connector_account = domain::MerchantConnectorAccount {
+        metadata: Some(Secret::new(metadata.clone())),
+        ..get_merchant_connector_account()
+    };
+
+    let list_item = api_models::admin::MerchantConnectorListResponse::foreign_try_from(
+        merchant_connector_account,
+    )
+    .unwrap();
+    assert_eq!(
+        list_item.connector_label,
+        Some("stripe_US_default".to_string())
+    );
+    assert_eq!(
+        list_item.metadata.as_ref().map(|metadata| metadata.peek()),
+        Some(&metadata)
+    );
+
+    let serialized_list_item = serde_json::to_value(list_item).unwrap();
+    assert_eq!(
+        serialized_list_item.get("metadata"),
+        Some(&serde_json::json!({ "environment": "staging" }))
+    );
+    assert!(serialized_list_item
+        .get("connector_account_details")
+        .is_none());
+    assert!(!serialized_list_item.to_string().contains("sk_test_secret"));
+}
+
+fn get_card_info(card_subtype: Option<&str>) -> diesel_models::cards_info::CardInfo {
+    diesel_models::cards_info::CardInfo {
+        card_iin: "411111".to_string(),
+        card_issuer: Some("JP MORGAN CHASE".to_string()),
+        card_network: Some(storage_enums::CardNetwork::Visa),
+        card_type: Some("CREDIT".to_string()),
+        card_subtype: card_subtype.map(ToString::to_string),
+        card_issuing_country: Some("UNITEDSTATES".to_string()),
+        bank_code_id: None,
+        bank_code: None,
+        country_code: None,
+        date_created: common_utils::date_time::now(),
+        last_updated: None,
+        last_updated_provider: None,
+    }
+}
+
+#[test]
+fn test_card_info_response_card_category() {
+    for (card_subtype, expected_card_category) in [
+        (Some("PLATINUM"), Some("premium")),
+        (Some("CLASSIC"), Some("standard")),
+        (None, None),
+    ] {
+        let card_info_response =
+            api_models::cards_info::CardInfoResponse::foreign_from(get_card_info(card_subtype));
+        assert_eq!(
+            card_info_response.card_category.as_deref(),
+            expected_card_category
+        );
+    }
+}
+
+#[cfg(feature = "olap")]
+fn get_event_list_constraints() -> api_types::webhook_events::EventListConstraints {
+    api_types::webhook_events::EventListConstraints {
+        created_after: None,
+        created_before: None,
+        limit: None,
+        offset: None,
+        object_id: None,
+        is_delivery_successful: None,
+        profile_id: None,
+    }
+}
+
+#[cfg(feature = "olap")]
+#[test]
+fn test_event_list_constraints_with_failed_deliveries_filter() {
+    let constraints = api_types::webhook_events::EventListConstraints {
+        is_delivery_successful: Some(false),
+        ..get_event_list_constraints()
+    };
+
+    let constraints =
+        api_types::webhook_events::EventListConstraintsInternal::foreign_try_from(constraints)
+            .unwrap();
+    assert!(matches!(
+        constraints,
+        api_types::webhook_events::EventListConstraintsInternal::GenericFilter {
+            is_delivery_successful: Some(false),
+            ..
+        }
+    ));
+}
+
+#[cfg(feature = "olap")]
+#[test]
+fn test_event_list_constraints_rejects_delivery_filter_with_object_id() {
+    let constraints = api_types::webhook_events::EventListConstraints {
+        object_id: Some("pay_test".to_string()),
+        is_delivery_successful: Some(false),
+        ..get_event_list_constraints()
+    };
+
+    let error =
+        api_types::webhook_events::EventListConstraintsInternal::foreign_try_from(constraints)
+            .unwrap_err();
+    assert!(matches!(
+        error.current_context(),
+        errors::ApiErrorResponse::PreconditionFailed { .. }
+    ));
+}
+
+#[cfg(all(
+    feature = "payouts",
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[test]
+fn test_bank_transfer_details_from_payment_method_data() {
+    let get_bank_payment_method_data = |payment_method_type: &str| {
+        serde_json::json!({
+            "BankDetails": {
+                "mask": "6789",
+                "hash": "bank_account_hash",
+                "account_type": "checking",
+                "account_name": "Plaid Checking",
+                "payment_method_type": payment_method_type,
+                "connector_details": []
+            }
+        })
+    };
+
+    let bank_transfer = get_bank_transfer_details_from_payment_method_data(Some(
+        get_bank_payment_method_data("sepa"),
+    ));
+    assert!(matches!(
+        bank_transfer,
+        Some(api_models::payouts::Bank::Sepa(sepa))
+            if sepa.iban.peek() == "6789" && sepa.bic.is_none()
+    ));
+
+    // The routing number required for ACH transfers is not stored
+    assert!(get_bank_transfer_details_from_payment_method_data(Some(
+        get_bank_payment_method_data("ach"),
+    ))
+    .is_none());
+}
+
+#[cfg(all(
+    feature = "payouts",
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[test]
+fn test_bank_transfer_details_from_card_payment_method_data() {
+    let card_payment_method_data = serde_json::json!({
+        "Card": {
+            "last4_digits": "1111",
+            "issuer_country": null,
+            "expiry_month": "10",
+            "expiry_year": "2030",
+            "nick_name": null,
+            "card_holder_name": null,
+            "card_isin": "411111",
+            "card_issuer": null,
+            "card_network": null,
+            "card_type": null,
+            "saved_to_locker": true
+        }
+    });
+
+    assert!(
+        get_bank_transfer_details_from_payment_method_data(Some(card_payment_method_data))
+            .is_none()
+    );
+}
+
+#[test]
+fn test_connector_response_status_to_attempt_status() {
+    for (connector_response_status, attempt_status) in [
+        (
+            crate::types::ConnectorResponseStatus::Approved,
+            storage_enums::AttemptStatus::Charged,
+        ),
+        (
+            crate::types::ConnectorResponseStatus::Declined,
+            storage_enums::AttemptStatus::Failure,
+        ),
+        (
+            crate::types::ConnectorResponseStatus::Pending,
+            storage_enums::AttemptStatus::Pending,
+        ),
+        (
+            crate::types::ConnectorResponseStatus::Authorized,
+            storage_enums::AttemptStatus::Authorized,
+        ),
+        (
+            crate::types::ConnectorResponseStatus::Voided,
+            storage_enums::AttemptStatus::Voided,
+        ),
+    ] {
+        assert_eq!(
+            storage_enums::AttemptStatus::foreign_from(connector_response_status),
+            attempt_status
+        );
+    }
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_dispute_response_with_connector_transaction_id() {
+    let payment_attempt = storage::PaymentAttempt {
+        connector_transaction_id: Some("pi_123".to_string()),
+        ..get_payment_attempt(
+            storage_enums::AttemptStatus::Charged,
+            Some(storage_enums::PaymentMethod::Card),
+        )
+    };
+
+    let dispute_response = api_models::disputes::DisputeResponse::foreign_from((
+        get_dispute(serde_json::json!({})),
+        Some(&payment_attempt),
+    ));
+    assert_eq!(
+        dispute_response.connector_transaction_id.as_deref(),
+        Some("pi_123")
+    );
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_dispute_response_without_resolvable_attempt() {
+    let dispute_response = api_models::disputes::DisputeResponse::foreign_from((
+        get_dispute(serde_json::json!({})),
+        None,
+    ));
+    assert_eq!(dispute_response.connector_transaction_id, None);
+
+    let other_payment_attempt = storage::PaymentAttempt {
+        attempt_id: "pay_attempt_2".to_string(),
+        connector_transaction_id: Some("pi_456".to_string()),
+        ..get_payment_attempt(
+            storage_enums::AttemptStatus::Charged,
+            Some(storage_enums::PaymentMethod::Card),
+        )
+    };
+
+    let dispute_response = api_models::disputes::DisputeResponse::foreign_from((
+        get_dispute(serde_json::json!({})),
+        Some(&other_payment_attempt),
+    ));
+    assert_eq!(dispute_response.connector_transaction_id, None);
+}
+
+#[test]
+fn test_gsm_grouped_response_by_connector() {
+    let adyen_gsm = storage::GatewayStatusMap {
+        connector: "adyen".to_string(),
+        ..get_gateway_status_map("requeue")
+    };
+    let gsm_rows = vec![
+        get_gateway_status_map("retry"),
+        adyen_gsm,
+        get_gateway_status_map("do_default"),
+    ];
+
+    let grouped_response = gsm_api_types::GsmGroupedResponse::foreign_try_from(gsm_rows).unwrap();
+    assert_eq!(grouped_response.connectors.len(), 2);
+
+    let stripe_decisions = grouped_response
+        .connectors
+        .get("stripe")
+        .unwrap()
+        .iter()
+        .map(|gsm| gsm.decision)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        stripe_decisions,
+        vec![
+            gsm_api_types::GsmDecision::Retry,
+            gsm_api_types::GsmDecision::DoDefault
+        ]
+    );
+    let adyen_decisions = grouped_response
+        .connectors
+        .get("adyen")
+        .unwrap()
+        .iter()
+        .map(|gsm| gsm.decision)
+        .collect::<Vec<_>>();
+    assert_eq!(adyen_decisions, vec![gsm_api_types::GsmDecision::Requeue]);
+}
+
+#[test]
+fn test_gsm_grouped_response_with_empty_input() {
+    let grouped_response = gsm_api_types::GsmGroupedResponse::foreign_try_from(Vec::new()).unwrap();
+    assert!(grouped_response.connectors.is_empty());
+}
+
+#[test]
+fn test_payment_source_from_optional_header_value() {
+    assert_eq!(
+        api_enums::PaymentSource::foreign_from(Some(api_enums::PaymentSource::Sdk)),
+        api_enums::PaymentSource::Sdk
+    );
+    assert_eq!(
+        api_enums::PaymentSource::foreign_from(None),
+        api_enums::PaymentSource::MerchantServer
+    );
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_header_payload_payment_confirm_source() {
+    use actix_web::http::header::{HeaderName, HeaderValue};
+
+    // The header constant is not lowercase, so it can't be used as a static header name
+    let confirm_source_header =
+        HeaderName::from_bytes(X_PAYMENT_CONFIRM_SOURCE.as_bytes()).unwrap();
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        confirm_source_header.clone(),
+        HeaderValue::from_static("dashboard"),
+    );
+    let header_payload =
+        hyperswitch_domain_models::payments::HeaderPayload::foreign_try_from(&headers).unwrap();
+    assert_eq!(
+        api_enums::PaymentSource::foreign_from(header_payload.payment_confirm_source),
+        api_enums::PaymentSource::Dashboard
+    );
+
+    let header_payload =
+        hyperswitch_domain_models::payments::HeaderPayload::foreign_try_from(&HeaderMap::new())
+            .unwrap();
+    assert_eq!(
+        api_enums::PaymentSource::foreign_from(header_payload.payment_confirm_source),
+        api_enums::PaymentSource::MerchantServer
+    );
+
+    for internal_source in ["webhook", "external_authenticator"] {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            confirm_source_header.clone(),
+            HeaderValue::from_static(internal_source),
+        );
+        assert!(
+            hyperswitch_domain_models::payments::HeaderPayload::foreign_try_from(&headers).is_err()
+        );
+    }
+}
+
+#[test]
+fn test_mandate_data_create_and_update_detection() {
+    let create_mandate_data =
+        hyperswitch_domain_models::mandates::MandateData::foreign_from(payments::MandateData {
+            update_mandate_id: None,
+            customer_acceptance: None,
+            mandate_type: Some(payments::MandateType::MultiUse(None)),
+        });
+    assert!(!create_mandate_data.is_update());
+
+    let update_mandate_data =
+        hyperswitch_domain_models::mandates::MandateData::foreign_from(payments::MandateData {
+            update_mandate_id: Some("man_123".to_string()),
+            customer_acceptance: None,
+            mandate_type: None,
+        });
+    assert!(update_mandate_data.is_update());
+    assert_eq!(
+        update_mandate_data.update_mandate_id.as_deref(),
+        Some("man_123")
+    );
+}
+
+#[test]
+fn test_payment_link_qr_payload() {
+    let qr_payload = payments::PaymentLinkQrPayload::foreign_from(get_payment_link(None));
+    assert_eq!(qr_payload.payment_link_id, "plink_test");
+    assert_eq!(
+        qr_payload.link_to_pay,
+        "https://example.com/payment_link/plink_test"
+    );
+    assert_eq!(qr_payload.amount, common_utils::types::MinorUnit::new(6540));
+    assert_eq!(qr_payload.currency, Some(storage_enums::Currency::USD));
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_payment_attempt_response_with_three_ds_connector_metadata() {
+    let connector_metadata = serde_json::json!({
+        "redirect_url": "https://acs.example.com/challenge",
+        "three_ds_version": "2.2.0",
+        "eci": "05",
+    });
+    let payment_attempt = storage::PaymentAttempt {
+        connector_metadata: Some(connector_metadata.clone()),
+        ..get_payment_attempt(
+            storage_enums::AttemptStatus::AuthenticationPending,
+            Some(storage_enums::PaymentMethod::Card),
+        )
+    };
+
+    let response = payments::PaymentAttemptResponse::foreign_from(payment_attempt);
+    assert_eq!(
+        response.parsed_connector_metadata,
+        Some(payments::ConnectorMetadataSummary {
+            redirect_url: Some("https://acs.example.com/challenge".to_string()),
+            three_ds_version: Some("2.2.0".to_string()),
+            eci: Some("05".to_string()),
+        })
+    );
+    assert_eq!(response.connector_metadata, Some(connector_metadata));
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_payment_attempt_response_with_unrecognized_connector_metadata() {
+    for connector_metadata in [
+        serde_json::json!({ "apple_pay": { "session_token_data": {} } }),
+        serde_json::json!({ "redirect_url": 42 }),
+        serde_json::json!(["psync_flow"]),
+    ] {
+        let payment_attempt = storage::PaymentAttempt {
+            connector_metadata: Some(connector_metadata.clone()),
+            ..get_payment_attempt(
+                storage_enums::AttemptStatus::Charged,
+                Some(storage_enums::PaymentMethod::Card),
+            )
+        };
+
+        let response = payments::PaymentAttemptResponse::foreign_from(payment_attempt);
+        assert_eq!(response.parsed_connector_metadata, None);
+        assert_eq!(response.connector_metadata, Some(connector_metadata));
+    }
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[test]
+fn test_mandate_eligibility_for_customer() {
+    let customer = domain::Customer {
+        name: Some(Encryptable::new(
+            Secret::new("John Doe".to_string()),
+            Secret::new(Vec::new()),
+        )),
+        email: Some(Encryptable::new(
+            Secret::new("john.doe@example.com".to_string()),
+            Secret::new(Vec::new()),
+        )),
+        phone: Some(Encryptable::new(
+            Secret::new("9123456789".to_string()),
+            Secret::new(Vec::new()),
+        )),
+        ..get_customer()
+    };
+    assert_eq!(
+        api_models::customers::MandateEligibility::foreign_from(&customer),
+        api_models::customers::MandateEligibility {
+            has_email: true,
+            has_phone: true,
+            has_name: true,
+        }
+    );
+
+    let customer_without_phone = domain::Customer {
+        phone: None,
+        ..customer
+    };
+    assert_eq!(
+        api_models::customers::MandateEligibility::foreign_from(&customer_without_phone),
+        api_models::customers::MandateEligibility {
+            has_email: true,
+            has_phone: false,
+            has_name: true,
+        }
+    );
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_payment_attempt_response_surcharge_breakdown() {
+    let payment_attempt = storage::PaymentAttempt {
+        net_amount: hyperswitch_domain_models::payments::payment_attempt::NetAmount::new(
+            common_utils::types::MinorUnit::new(6540),
+            None,
+            None,
+            Some(common_utils::types::MinorUnit::new(100)),
+            Some(common_utils::types::MinorUnit::new(18)),
+        ),
+        ..get_payment_attempt(
+            storage_enums::AttemptStatus::Charged,
+            Some(storage_enums::PaymentMethod::Card),
+        )
+    };
+
+    let response = payments::PaymentAttemptResponse::foreign_from(payment_attempt);
+    assert_eq!(response.amount, common_utils::types::MinorUnit::new(6540));
+    assert_eq!(
+        response.surcharge_amount,
+        Some(common_utils::types::MinorUnit::new(100))
+    );
+    assert_eq!(
+        response.tax_on_surcharge_amount,
+        Some(common_utils::types::MinorUnit::new(18))
+    );
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_payment_attempt_response_without_surcharge() {
+    let response = payments::PaymentAttemptResponse::foreign_from(get_payment_attempt(
+        storage_enums::AttemptStatus::Charged,
+        Some(storage_enums::PaymentMethod::Card),
+    ));
+    assert_eq!(response.surcharge_amount, None);
+    assert_eq!(response.tax_on_surcharge_amount, None);
+}
+
+#[test]
+fn test_routing_algorithm_priority_connector_list() {
+    let routing_algorithm = routing_types::RoutingAlgorithm::Priority(
+        [
+            api_enums::RoutableConnectors::Adyen,
+            api_enums::RoutableConnectors::Stripe,
+            api_enums::RoutableConnectors::Checkout,
+        ]
+        .into_iter()
+        .map(|connector| routing_types::RoutableConnectorChoice {
+            choice_kind: api_models::routing::RoutableChoiceKind::FullStruct,
+            connector,
+            merchant_connector_id: None,
+        })
+        .collect(),
+    );
+    assert_eq!(
+        Vec::<common_enums::RoutableConnectors>::foreign_from(&routing_algorithm),
+        vec![
+            api_enums::RoutableConnectors::Adyen,
+            api_enums::RoutableConnectors::Stripe,
+            api_enums::RoutableConnectors::Checkout,
+        ]
+    );
+}
+
+#[test]
+fn test_routing_algorithm_volume_split_connector_list() {
+    let mut splits = get_volume_splits(&[70, 30]);
+    splits.first_mut().unwrap().connector.connector = api_enums::RoutableConnectors::Checkout;
+    let routing_algorithm = routing_types::RoutingAlgorithm::VolumeSplit(splits);
+    assert_eq!(
+        Vec::<common_enums::RoutableConnectors>::foreign_from(&routing_algorithm),
+        vec![
+            api_enums::RoutableConnectors::Checkout,
+            api_enums::RoutableConnectors::Stripe,
+        ]
+    );
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_safe_connector_debug_omits_account_details() {
+    let safe_connector_debug =
+        crate::types::SafeConnectorDebug::foreign_from(&get_merchant_connector_account());
+    let debug_view = format!("{safe_connector_debug:?}");
+    assert!(debug_view.contains("stripe"));
+    assert!(debug_view.contains("mca_test"));
+    assert!(!debug_view.contains("sk_test_secret"));
+    assert!(!debug_view.contains("whsec_secret"));
+}
+
+fn get_mandate_amount_data(
+    start_date: Option<time::PrimitiveDateTime>,
+    end_date: Option<time::PrimitiveDateTime>,
+) -> payments::MandateAmountData {
+    payments::MandateAmountData {
+        amount: common_utils::types::MinorUnit::new(6540),
+        currency: api_enums::Currency::USD,
+        start_date,
+        end_date,
+        metadata: None,
+    }
+}
+
+#[test]
+fn test_mandate_amount_data_with_ordered_dates() {
+    let start_date = common_utils::date_time::now();
+    let end_date = start_date + time::Duration::days(30);
+
+    let storage_mandate_amount_data = storage_enums::MandateAmountData::foreign_try_from(
+        get_mandate_amount_data(Some(start_date), Some(end_date)),
+    )
+    .unwrap();
+    assert_eq!(storage_mandate_amount_data.start_date, Some(start_date));
+    assert_eq!(storage_mandate_amount_data.end_date, Some(end_date));
+}
+
+#[test]
+fn test_mandate_amount_data_with_inverted_dates() {
+    let start_date = common_utils::date_time::now();
+    let end_date = start_date - time::Duration::days(1);
+
+    let error = storage_enums::MandateAmountData::foreign_try_from(get_mandate_amount_data(
+        Some(start_date),
+        Some(end_date),
+    ))
+    .unwrap_err();
+    assert!(matches!(
+        error.current_context(),
+        common_utils::errors::ValidationError::InvalidValue { .. }
+    ));
+
+    assert!(
+        storage_enums::MandateDataType::foreign_try_from(payments::MandateType::SingleUse(
+            get_mandate_amount_data(Some(start_date), Some(end_date))
+        ))
+        .is_err()
+    );
+}
+
+#[test]
+fn test_mandate_amount_data_without_end_date() {
+    let start_date = common_utils::date_time::now();
+
+    let storage_mandate_amount_data = storage_enums::MandateAmountData::foreign_try_from(
+        get_mandate_amount_data(Some(start_date), None),
+    )
+    .unwrap();
+    assert_eq!(storage_mandate_amount_data.start_date, Some(start_date));
+    assert_eq!(storage_mandate_amount_data.end_date, None);
+}
+
+#[cfg(feature = "olap")]
+#[test]
+fn test_replayable_webhook_request_from_event() {
+    let event = domain::Event {
+        request: Some(Encryptable::new(
+            Secret::new(
+                serde_json::json!({
+                    "body": r#"{"event_id":"evt_1"}"#,
+                    "headers": [["content-type", "application/json"]],
+                    "url": "https://example.com/webhooks",
+                })
+                .to_string(),
+            ),
+            Secret::new(Vec::new()),
+        )),
+        ..get_event("evt_1", "evt_1")
+    };
+
+    let replayable_request = crate::types::ReplayableWebhookRequest::try_from(event).unwrap();
+    assert_eq!(replayable_request.url, "https://example.com/webhooks");
+    assert_eq!(replayable_request.body.peek(), r#"{"event_id":"evt_1"}"#);
+    assert_eq!(
+        replayable_request
+            .headers
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.peek().as_str()))
+            .collect::<Vec<_>>(),
+        vec![("content-type", "application/json")]
+    );
+}
+
+#[cfg(feature = "olap")]
+#[test]
+fn test_replayable_webhook_request_without_url() {
+    assert!(crate::types::ReplayableWebhookRequest::try_from(get_event("evt_1", "evt_1")).is_err());
+}
+
+#[test]
+fn test_payment_method_type_supported_countries() {
+    assert_eq!(
+        Vec::<common_enums::CountryAlpha2>::foreign_from(api_enums::PaymentMethodType::Ideal),
+        vec![common_enums::CountryAlpha2::NL]
+    );
+    assert_eq!(
+        Vec::<common_enums::CountryAlpha2>::foreign_from(api_enums::PaymentMethodType::Boleto),
+        vec![common_enums::CountryAlpha2::BR]
+    );
+    assert!(
+        Vec::<common_enums::CountryAlpha2>::foreign_from(api_enums::PaymentMethodType::Credit)
+            .is_empty()
+    );
+}
+
+fn get_refund(refund_id: &str) -> storage::Refund {
+    let current_time = common_utils::date_time::now();
+    storage::Refund {
+        internal_reference_id: "refid_1".to_string(),
+        refund_id: refund_id.to_string(),
+        payment_id: common_utils::id_type::PaymentId::default(),
+        merchant_id: common_utils::id_type::MerchantId::default(),
+        connector_transaction_id: common_utils::types::ConnectorTransactionId::from(
+            "txn_1".to_string(),
+        ),
+        connector: "stripe".to_string(),
+        connector_refund_id: None,
+        external_reference_id: None,
+        refund_type: storage_enums::RefundType::InstantRefund,
+        total_amount: common_utils::types::MinorUnit::new(6540),
+        currency: storage_enums::Currency::USD,
+        refund_amount: common_utils::types::MinorUnit::new(6540),
+        refund_status: storage_enums::RefundStatus::Success,
+        sent_to_gateway: true,
+        refund_error_message: None,
+        metadata: None,
+        refund_arn: None,
+        created_at: current_time,
+        modified_at: current_time,
+        description: None,
+        attempt_id: "pay_attempt_1".to_string(),
+        refund_reason: None,
+        refund_error_code: None,
+        profile_id: None,
+        updated_by: "admin".to_string(),
+        merchant_connector_id: None,
+        charges: None,
+        organization_id: common_utils::id_type::OrganizationId::default(),
+        connector_refund_data: None,
+        connector_transaction_data: None,
+    }
+}
+
+#[test]
+fn test_phone_details_normalized_to_e164() {
+    let address = domain::Address {
+        phone_number: Some(Encryptable::new(
+            Secret::new("(415) 555-2671".to_string()),
+            Secret::new(Vec::new()),
+        )),
+        country_code: Some("1".to_string()),
+        ..get_empty_address()
+    };
+
+    let phone_details = api_types::PhoneDetails::foreign_try_from(&address).unwrap();
+    assert_eq!(
+        phone_details.number.map(|number| number.expose()),
+        Some("4155552671".to_string())
+    );
+    assert_eq!(phone_details.country_code, Some("+1".to_string()));
+}
+
+#[test]
+fn test_phone_details_rejects_invalid_number() {
+    let address = domain::Address {
+        phone_number: Some(Encryptable::new(
+            Secret::new("415-CALL-NOW".to_string()),
+            Secret::new(Vec::new()),
+        )),
+        country_code: Some("+1".to_string()),
+        ..get_empty_address()
+    };
+
+    let error = api_types::PhoneDetails::foreign_try_from(&address).unwrap_err();
+    assert!(matches!(
+        error.current_context(),
+        common_utils::errors::ValidationError::InvalidValue { .. }
+    ));
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_enabled_payment_methods_of_connector() {
+    let merchant_connector_account = domain::MerchantConnectorAccount {
+        payment_methods_enabled: Some(vec![
+            Secret::new(serde_json::json!({
+                "payment_method": "card",
+                "payment_method_types": [{
+                    "payment_method_type": "credit",
+                    "recurring_enabled": true,
+                    "installment_payment_enabled": false
+                }]
+            })),
+            Secret::new(serde_json::json!({
+                "payment_method": "wallet",
+                "payment_method_types": [{
+                    "payment_method_type": "apple_pay",
+                    "recurring_enabled": true,
+                    "installment_payment_enabled": false
+                }]
+            })),
+        ]),
+        ..get_merchant_connector_account()
+    };
+
+    let payment_methods = std::collections::HashSet::<api_enums::PaymentMethod>::foreign_try_from(
+        &merchant_connector_account,
+    )
+    .unwrap();
+    assert_eq!(
+        payment_methods,
+        std::collections::HashSet::from([
+            api_enums::PaymentMethod::Card,
+            api_enums::PaymentMethod::Wallet
+        ])
+    );
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_enabled_payment_methods_of_connector_without_any() {
+    let merchant_connector_account = domain::MerchantConnectorAccount {
+        payment_methods_enabled: None,
+        ..get_merchant_connector_account()
+    };
+
+    let payment_methods = std::collections::HashSet::<api_enums::PaymentMethod>::foreign_try_from(
+        &merchant_connector_account,
+    )
+    .unwrap();
+    assert!(payment_methods.is_empty());
+}
+
+#[test]
+fn test_dispute_stage_forward_transition() {
+    let dispute_stage = storage_enums::DisputeStage::foreign_try_from((
+        storage_enums::DisputeStage::PreDispute,
+        storage_enums::DisputeStage::Dispute,
+    ))
+    .unwrap();
+    assert_eq!(dispute_stage, storage_enums::DisputeStage::Dispute);
+}
+
+#[test]
+fn test_dispute_stage_backward_transition_is_rejected() {
+    let error = storage_enums::DisputeStage::foreign_try_from((
+        storage_enums::DisputeStage::PreArbitration,
+        storage_enums::DisputeStage::Dispute,
+    ))
+    .unwrap_err();
+    assert!(matches!(
+        error.current_context(),
+        errors::ApiErrorResponse::PreconditionFailed { .. }
+    ));
+}
+
+#[test]
+fn test_api_key_audit_log_entry_omits_key_material() {
+    let api_key = get_api_key(None);
+
+    let audit_log_entry =
+        router_types::AuditLogEntry::foreign_from((&api_key, router_types::AuditAction::Retrieve));
+    let serialized_entry = serde_json::to_value(&audit_log_entry).unwrap().to_string();
+
+    assert!(serialized_entry.contains("dev_test"));
+    assert!(serialized_entry.contains("retrieve"));
+    assert!(!serialized_entry.contains("hashed_key"));
+    assert!(!serialized_entry.contains("dev_abc"));
+}
+
+#[test]
+fn test_capture_response_created_at() {
+    let capture = get_capture(
+        1,
+        storage_enums::CaptureStatus::Charged,
+        1000,
+        storage_enums::Currency::USD,
+    );
+    let created_at = capture.created_at;
+
+    let capture_response = payments::CaptureResponse::foreign_from(capture);
+    assert_eq!(capture_response.created_at, created_at);
+
+    let serialized_response = serde_json::to_value(capture_response).unwrap();
+    assert!(serialized_response.get("created_at").is_some());
+}
+
+#[test]
+fn test_incoming_webhook_event_class_and_type() {
+    for (incoming_webhook_event, expected_event_class_and_type) in [
+        (
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentSuccess,
+            (
+                storage_enums::EventClass::Payments,
+                storage_enums::EventType::PaymentSucceeded,
+            ),
+        ),
+        (
+            api_models::webhooks::IncomingWebhookEvent::RefundFailure,
+            (
+                storage_enums::EventClass::Refunds,
+                storage_enums::EventType::RefundFailed,
+            ),
+        ),
+        (
+            api_models::webhooks::IncomingWebhookEvent::DisputeWon,
+            (
+                storage_enums::EventClass::Disputes,
+                storage_enums::EventType::DisputeWon,
+            ),
+        ),
+        (
+            api_models::webhooks::IncomingWebhookEvent::MandateRevoked,
+            (
+                storage_enums::EventClass::Mandates,
+                storage_enums::EventType::MandateRevoked,
+            ),
+        ),
+    ] {
+        assert_eq!(
+            <(storage_enums::EventClass, storage_enums::EventType)>::foreign_try_from(
+                incoming_webhook_event
+            )
+            .unwrap(),
+            expected_event_class_and_type
+        );
+    }
+}
+
+#[test]
+fn test_unmapped_incoming_webhook_event_class_and_type() {
+    assert!(matches!(
+        <(storage_enums::EventClass, storage_enums::EventType)>::foreign_try_from(
+            api_models::webhooks::IncomingWebhookEvent::EndpointVerification
+        ),
+        Err(errors::ValidationError::IncorrectValueProvided { .. })
+    ));
+}
+
+#[test]
+fn test_mandate_amount_remaining() {
+    let mandate_amount_data =
+        storage_enums::MandateAmountData::foreign_try_from(get_mandate_amount_data(None, None))
+            .unwrap();
+
+    for (consumed, expected_remaining) in [(1000, 5540), (6540, 0), (8000, 0)] {
+        let mandate_amount_remaining = payments::MandateAmountRemaining::foreign_from((
+            &mandate_amount_data,
+            common_utils::types::MinorUnit::new(consumed),
+        ));
+        assert_eq!(
+            mandate_amount_remaining.amount,
+            common_utils::types::MinorUnit::new(expected_remaining)
+        );
+        assert_eq!(mandate_amount_remaining.currency, api_enums::Currency::USD);
+    }
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_network_transaction_context_with_network_transaction_id() {
+    let payment_attempt = storage::PaymentAttempt {
+        connector_transaction_id: Some("pi_123".to_string()),
+        connector_metadata: Some(serde_json::json!({
+            "network_transaction_id": "ntid_123"
+        })),
+        ..get_payment_attempt(storage_enums::AttemptStatus::Charged, None)
+    };
+
+    let network_transaction_context =
+        Option::<payments::NetworkTransactionContext>::foreign_from(&payment_attempt).unwrap();
+    assert_eq!(
+        network_transaction_context.connector_transaction_id,
+        "pi_123"
+    );
+    assert_eq!(
+        network_transaction_context.network_transaction_id,
+        Some("ntid_123".to_string())
+    );
+    assert_eq!(
+        network_transaction_context.connector,
+        Some("stripe".to_string())
+    );
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_network_transaction_context_without_network_transaction_id() {
+    let mut payment_attempt = storage::PaymentAttempt {
+        connector_transaction_id: Some("pi_123".to_string()),
+        ..get_payment_attempt(storage_enums::AttemptStatus::Charged, None)
+    };
+
+    let network_transaction_context =
+        Option::<payments::NetworkTransactionContext>::foreign_from(&payment_attempt).unwrap();
+    assert_eq!(network_transaction_context.network_transaction_id, None);
+
+    payment_attempt.connector_transaction_id = None;
+    assert_eq!(
+        Option::<payments::NetworkTransactionContext>::foreign_from(&payment_attempt),
+        None
+    );
+}
+
+#[test]
+fn test_org_created_event() {
+    let org_new = diesel_models::organization::OrganizationNew::foreign_from(
+        api_models::organization::OrganizationNew::new(Some("org_name".to_string())),
+    );
+
+    let org_created_event = router_types::OrgCreatedEvent::foreign_from(&org_new);
+    assert_eq!(org_created_event.org_id, org_new.get_organization_id());
+    assert_eq!(org_created_event.org_name, Some("org_name".to_string()));
+    assert_eq!(org_created_event.created_at, org_new.created_at);
+}
+
+#[test]
+fn test_mandate_data_with_matching_currency() {
+    let mandate_data = payments::MandateData {
+        update_mandate_id: None,
+        customer_acceptance: None,
+        mandate_type: Some(payments::MandateType::MultiUse(Some(
+            get_mandate_amount_data(None, None),
+        ))),
+    };
+
+    let domain_mandate_data = hyperswitch_domain_models::mandates::MandateData::foreign_try_from((
+        mandate_data,
+        Some(common_enums::Currency::USD),
+    ))
+    .unwrap();
+    assert!(domain_mandate_data.mandate_type.is_some());
+}
+
+#[test]
+fn test_mandate_data_with_mismatching_currency() {
+    let mandate_data = payments::MandateData {
+        update_mandate_id: None,
+        customer_acceptance: None,
+        mandate_type: Some(payments::MandateType::MultiUse(Some(
+            get_mandate_amount_data(None, None),
+        ))),
+    };
+
+    let error = hyperswitch_domain_models::mandates::MandateData::foreign_try_from((
+        mandate_data,
+        Some(common_enums::Currency::EUR),
+    ))
+    .unwrap_err();
+    assert!(matches!(
+        error.current_context(),
+        common_utils::errors::ValidationError::InvalidValue { .. }
+    ));
+}
+
+#[test]
+fn test_mandate_data_with_inverted_dates() {
+    let start_date = common_utils::date_time::now();
+    let mandate_data = payments::MandateData {
+        update_mandate_id: None,
+        customer_acceptance: None,
+        mandate_type: Some(payments::MandateType::SingleUse(get_mandate_amount_data(
+            Some(start_date),
+            Some(start_date - time::Duration::days(1)),
+        ))),
+    };
+
+    let error =
+        hyperswitch_domain_models::mandates::MandateData::foreign_try_from((mandate_data, None))
+            .unwrap_err();
+    assert!(matches!(
+        error.current_context(),
+        common_utils::errors::ValidationError::InvalidValue { .. }
+    ));
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_payment_method_capability_matrix() {
+    let card_and_wallet_connector = domain::MerchantConnectorAccount {
+        merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId::wrap(
+            "mca_1".to_string(),
+        )
+        .unwrap(),
+        payment_methods_enabled: Some(vec![
+            Secret::new(serde_json::json!({ "payment_method": "card" })),
+            Secret::new(serde_json::json!({ "payment_method": "wallet" })),
+        ]),
+        ..get_merchant_connector_account()
+    };
+    let card_and_bank_redirect_connector = domain::MerchantConnectorAccount {
+        merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId::wrap(
+            "mca_2".to_string(),
+        )
+        .unwrap(),
+        payment_methods_enabled: Some(vec![
+            Secret::new(serde_json::json!({ "payment_method": "card" })),
+            Secret::new(serde_json::json!({ "payment_method": "bank_redirect" })),
+        ]),
+        ..get_merchant_connector_account()
+    };
+
+    let capability_matrix =
+        api_models::admin::PaymentMethodCapabilityMatrix::foreign_try_from(vec![
+            card_and_wallet_connector,
+            card_and_bank_redirect_connector,
+        ])
+        .unwrap();
+    let connector_ids = |payment_method: api_enums::PaymentMethod| {
+        capability_matrix
+            .payment_methods
+            .get(&payment_method)
+            .map(|ids| {
+                ids.iter()
+                    .map(|id| id.get_string_repr().to_string())
+                    .collect::<Vec<_>>()
+            })
+    };
+
+    assert_eq!(
+        connector_ids(api_enums::PaymentMethod::Card),
+        Some(vec!["mca_1".to_string(), "mca_2".to_string()])
+    );
+    assert_eq!(
+        connector_ids(api_enums::PaymentMethod::Wallet),
+        Some(vec!["mca_1".to_string()])
+    );
+    assert_eq!(
+        connector_ids(api_enums::PaymentMethod::BankRedirect),
+        Some(vec!["mca_2".to_string()])
+    );
+    assert_eq!(connector_ids(api_enums::PaymentMethod::PayLater), None);
+}
+
+#[test]
+fn test_attempt_status_retry_eligibility() {
+    for (status, expected_retry_eligibility) in [
+        (
+            storage_enums::AttemptStatus::AuthorizationFailed,
+            router_types::RetryEligibility::Retryable,
+        ),
+        (
+            storage_enums::AttemptStatus::Charged,
+            router_types::RetryEligibility::Terminal,
+        ),
+        (
+            storage_enums::AttemptStatus::Pending,
+            router_types::RetryEligibility::InProgress,
+        ),
+    ] {
+        assert_eq!(
+            router_types::RetryEligibility::foreign_from(status),
+            expected_retry_eligibility
+        );
+    }
+}
+
+#[test]
+fn test_refund_ledger_row() {
+    let refund = storage::Refund {
+        refund_amount: common_utils::types::MinorUnit::new(1000),
+        ..get_refund("ref_1")
+    };
+
+    let refund_ledger_row = router_types::RefundLedgerRow::foreign_from(&refund);
+    assert_eq!(refund_ledger_row.refund_id, "ref_1");
+    assert_eq!(
+        refund_ledger_row.amount,
+        common_utils::types::MinorUnit::new(1000)
+    );
+    assert_eq!(refund_ledger_row.status, "success");
+    assert_eq!(refund_ledger_row.created_at, refund.created_at);
+}
+
+#[test]
+fn test_external_authentication_details_supports_3ds2() {
+    for (maximum_supported_version, expected_supports_3ds2) in [
+        (
+            Some(common_utils::types::SemanticVersion::new(2, 2, 0)),
+            true,
+        ),
+        (
+            Some(common_utils::types::SemanticVersion::new(1, 0, 2)),
+            false,
+        ),
+        (None, false),
+    ] {
+        let authentication = storage::Authentication {
+            maximum_supported_version,
+            ..get_authentication()
+        };
+
+        let authentication_details =
+            payments::ExternalAuthenticationDetailsResponse::foreign_from(&authentication);
+        assert_eq!(authentication_details.supports_3ds2, expected_supports_3ds2);
+    }
+}
+
+#[test]
+fn test_connector_webhook_verification_scheme() {
+    let headers = HeaderMap::new();
+    let request = api_types::IncomingWebhookRequestDetails {
+        method: actix_web::http::Method::POST,
+        uri: actix_web::http::Uri::from_static("/webhooks"),
+        headers: &headers,
+        body: &[],
+        query_params: String::new(),
+    };
+    let scheme_for = |connector: &dyn api_types::IncomingWebhook| {
+        let algorithm = connector
+            .get_webhook_source_verification_algorithm(&request)
+            .unwrap();
+        router_types::WebhookVerificationScheme::foreign_from(algorithm.as_ref())
+    };
+
+    assert_eq!(
+        scheme_for(crate::connector::Stripe::new()),
+        router_types::WebhookVerificationScheme::Hmac
+    );
+    assert_eq!(
+        scheme_for(crate::connector::Novalnet::new()),
+        router_types::WebhookVerificationScheme::SignatureHeader
+    );
+    assert_eq!(
+        router_types::WebhookVerificationScheme::foreign_from(
+            &common_utils::crypto::NoAlgorithm
+                as &(dyn common_utils::crypto::VerifySignature + Send)
+        ),
+        router_types::WebhookVerificationScheme::Unverified
+    );
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_browser_information_from_payment_attempt() {
+    let payment_attempt = storage::PaymentAttempt {
+        browser_info: Some(serde_json::json!({
+            "user_agent": "Mozilla/5.0",
+            "accept_header": "text/html",
+            "language": "en-US",
+            "screen_height": 1080,
+            "screen_width": 1920
+        })),
+        ..get_payment_attempt(storage_enums::AttemptStatus::Pending, None)
+    };
+
+    let browser_info =
+        Option::<payments::BrowserInformation>::foreign_from(&payment_attempt).unwrap();
+    assert_eq!(browser_info.user_agent, Some("Mozilla/5.0".to_string()));
+    assert_eq!(browser_info.accept_header, Some("text/html".to_string()));
+    assert_eq!(browser_info.language, Some("en-US".to_string()));
+    assert_eq!(browser_info.screen_height, Some(1080));
+    assert_eq!(browser_info.screen_width, Some(1920));
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_browser_information_from_payment_attempt_without_browser_info() {
+    let mut payment_attempt = get_payment_attempt(storage_enums::AttemptStatus::Pending, None);
+    assert!(Option::<payments::BrowserInformation>::foreign_from(&payment_attempt).is_none());
+
+    payment_attempt.browser_info = Some(serde_json::json!({}));
+    assert!(Option::<payments::BrowserInformation>::foreign_from(&payment_attempt).is_none());
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_merchant_connector_masked_response() {
+    let merchant_connector_account = domain::MerchantConnectorAccount {
+        connector_account_details: Encryptable::new(
+            Secret::new(serde_json::json!({
+                "auth_type": "SignatureKey",
+                "api_key": "sk_test_secret",
+                "key1": { "nested_secret": "nested_value", "key_ids": [1, 2] }
+            })),
+            Secret::new(Vec::new()),
+        ),
+        ..get_merchant_connector_account()
+    };
+
+    let masked_response = api_models::admin::MerchantConnectorMaskedResponse::foreign_from(
+        merchant_connector_account,
+    );
+    assert_eq!(
+        masked_response.connector_account_details,
+        serde_json::json!({
+            "auth_type": "***",
+            "api_key": "***",
+            "key1": { "nested_secret": "***", "key_ids": ["***", "***"] }
+        })
+    );
+    assert!(!serde_json::to_string(&masked_response)
+        .unwrap()
+        .contains("sk_test_secret"));
+}
+
+#[test]
+fn test_dispute_response_urgency() {
+    let now = common_utils::date_time::now();
+    for (challenge_required_by, expected_urgency) in [
+        (
+            Some(now - time::Duration::hours(1)),
+            api_models::disputes::DisputeUrgency::Overdue,
+        ),
+        (
+            Some(now + time::Duration::hours(24)),
+            api_models::disputes::DisputeUrgency::DueSoon,
+        ),
+        (
+            Some(now + time::Duration::hours(72)),
+            api_models::disputes::DisputeUrgency::Ample,
+        ),
+        (None, api_models::disputes::DisputeUrgency::NoDeadline),
+    ] {
+        let dispute = storage::Dispute {
+            challenge_required_by,
+            ..get_dispute(serde_json::json!({}))
+        };
+
+        let dispute_response = api_models::disputes::DisputeResponse::foreign_from(dispute);
+        assert_eq!(dispute_response.urgency, expected_urgency);
+    }
+}
+
+#[test]
+fn test_payment_link_status_from_payment_link() {
+    let now = common_utils::date_time::now();
+
+    let active_payment_link = get_payment_link(Some(now + time::Duration::minutes(15)));
+    assert_eq!(
+        payments::PaymentLinkStatus::foreign_from(&active_payment_link),
+        payments::PaymentLinkStatus::Active
+    );
+
+    let expired_payment_link = get_payment_link(Some(now - time::Duration::minutes(15)));
+    assert_eq!(
+        payments::PaymentLinkStatus::foreign_from(&expired_payment_link),
+        payments::PaymentLinkStatus::Expired
+    );
+    assert_eq!(
+        payments::RetrievePaymentLinkResponse::foreign_from(expired_payment_link).status,
+        payments::PaymentLinkStatus::Expired
+    );
+}
+
+#[test]
+fn test_payment_link_status_without_fulfilment_time() {
+    let recent_payment_link = get_payment_link(None);
+    assert_eq!(
+        payments::PaymentLinkStatus::foreign_from(&recent_payment_link),
+        payments::PaymentLinkStatus::Active
+    );
+
+    let stale_payment_link = storage::PaymentLink {
+        created_at: common_utils::date_time::now() - time::Duration::hours(1),
+        ..get_payment_link(None)
+    };
+    assert_eq!(
+        payments::PaymentLinkStatus::foreign_from(&stale_payment_link),
+        payments::PaymentLinkStatus::Expired
+    );
+}
+
+#[test]
+fn test_authorization_error_for_failed_authorization() {
+    let authorization = storage::Authorization {
+        error_code: Some("card_declined".to_string()),
+        error_message: Some("Insufficient funds".to_string()),
+        ..get_authorization(
+            "auth_1",
+            7000,
+            6540,
+            storage_enums::AuthorizationStatus::Failure,
+            common_utils::date_time::now(),
+        )
+    };
+
+    let error = Option::<payments::AuthorizationError>::foreign_from(&authorization).unwrap();
+    assert_eq!(error.code.as_deref(), Some("card_declined"));
+    assert_eq!(error.message.as_deref(), Some("Insufficient funds"));
+
+    let response = payments::IncrementalAuthorizationResponse::foreign_from(authorization);
+    assert_eq!(response.error_code.as_deref(), Some("card_declined"));
+    assert_eq!(
+        response.error_message.as_deref(),
+        Some("Insufficient funds")
+    );
+}
+
+#[test]
+fn test_authorization_error_for_successful_authorization() {
+    let authorization = get_authorization(
+        "auth_1",
+        7000,
+        6540,
+        storage_enums::AuthorizationStatus::Success,
+        common_utils::date_time::now(),
+    );
+
+    assert_eq!(
+        Option::<payments::AuthorizationError>::foreign_from(&authorization),
+        None
+    );
+}
+
+#[test]
+fn test_connector_currency_support_for_restricted_connector() {
+    let pm_filters = settings::ConnectorFilters(std::collections::HashMap::from([(
+        "razorpay".to_string(),
+        settings::PaymentMethodFilters(std::collections::HashMap::from([(
+            settings::PaymentMethodFilterKey::PaymentMethodType(
+                api_enums::PaymentMethodType::UpiCollect,
+            ),
+            settings::CurrencyCountryFlowFilter {
+                currency: Some(std::collections::HashSet::from([
+                    common_enums::Currency::INR,
+                ])),
+                ..Default::default()
+            },
+        )])),
+    )]));
+
+    let currency_support = router_types::ConnectorCurrencySupport::foreign_from((
+        api_enums::Connector::Razorpay,
+        &pm_filters,
+    ));
+    assert_eq!(
+        currency_support,
+        router_types::ConnectorCurrencySupport::Only(std::collections::HashSet::from([
+            common_enums::Currency::INR
+        ]))
+    );
+    assert!(currency_support.is_supported(common_enums::Currency::INR));
+    assert!(!currency_support.is_supported(common_enums::Currency::USD));
+}
+
+#[test]
+fn test_connector_currency_support_for_unrestricted_connector() {
+    let currency_support = router_types::ConnectorCurrencySupport::foreign_from((
+        api_enums::Connector::Stripe,
+        &settings::ConnectorFilters::default(),
+    ));
+    assert_eq!(
+        currency_support,
+        router_types::ConnectorCurrencySupport::All
+    );
+    assert!(currency_support.is_supported(common_enums::Currency::USD));
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[test]
+fn test_masked_customer_details_with_full_email() {
+    let customer = domain::Customer {
+        email: Some(Encryptable::new(
+            Secret::new("john.doe@example.com".to_string()),
+            Secret::new(Vec::new()),
+        )),
+        phone: Some(Encryptable::new(
+            Secret::new("9123456789".to_string()),
+            Secret::new(Vec::new()),
+        )),
+        ..get_customer()
+    };
+
+    let masked_details = router_types::MaskedCustomerDetails::foreign_from(&customer);
+    assert_eq!(masked_details.email.as_deref(), Some("j***@example.com"));
+    assert_eq!(masked_details.phone.as_deref(), Some("***6789"));
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[test]
+fn test_masked_customer_details_with_short_email() {
+    let customer = domain::Customer {
+        email: Some(Encryptable::new(
+            Secret::new("j@example.com".to_string()),
+            Secret::new(Vec::new()),
+        )),
+        ..get_customer()
+    };
+
+    let masked_details = router_types::MaskedCustomerDetails::foreign_from(&customer);
+    assert_eq!(masked_details.email.as_deref(), Some("***@example.com"));
+    assert_eq!(masked_details.phone, None);
+}
+
+#[cfg(feature = "olap")]
+#[test]
+fn test_event_list_item_response_status_code_for_successful_delivery() {
+    let response =
+        api_models::webhook_events::EventListItemResponse::try_from(get_event("evt_1", "evt_1"))
+            .unwrap();
+    assert_eq!(response.response_status_code, Some(200));
+}
+
+#[cfg(feature = "olap")]
+#[test]
+fn test_event_list_item_response_status_code_for_failed_delivery() {
+    let event = domain::Event {
+        is_webhook_notified: false,
+        response: Some(Encryptable::new(
+            Secret::new(r#"{"body":null,"headers":null,"status_code":500}"#.to_string()),
+            Secret::new(Vec::new()),
+        )),
+        ..get_event("evt_1", "evt_1")
+    };
+
+    let response = api_models::webhook_events::EventListItemResponse::try_from(event).unwrap();
+    assert_eq!(response.response_status_code, Some(500));
+    assert!(!response.is_delivery_successful);
+}
+
+#[cfg(feature = "olap")]
+#[test]
+fn test_event_list_item_response_status_code_for_unattempted_delivery() {
+    let event = domain::Event {
+        is_webhook_notified: false,
+        response: None,
+        ..get_event("evt_1", "evt_1")
+    };
+
+    let response = api_models::webhook_events::EventListItemResponse::try_from(event).unwrap();
+    assert_eq!(response.response_status_code, None);
+}
+
+#[test]
+fn test_fallback_chain_starts_with_primary_connector() {
+    let default_fallback = [
+        common_enums::RoutableConnectors::Stripe,
+        common_enums::RoutableConnectors::Adyen,
+        common_enums::RoutableConnectors::Stripe,
+    ]
+    .into_iter()
+    .map(|connector| routing_types::RoutableConnectorChoice {
+        choice_kind: api_models::routing::RoutableChoiceKind::FullStruct,
+        connector,
+        merchant_connector_id: None,
+    })
+    .collect::<Vec<_>>();
+
+    for (primary_connector, expected_chain) in [
+        (
+            common_enums::RoutableConnectors::Adyen,
+            vec![
+                common_enums::RoutableConnectors::Adyen,
+                common_enums::RoutableConnectors::Stripe,
+            ],
+        ),
+        (
+            common_enums::RoutableConnectors::Paypal,
+            vec![
+                common_enums::RoutableConnectors::Paypal,
+                common_enums::RoutableConnectors::Stripe,
+                common_enums::RoutableConnectors::Adyen,
+            ],
+        ),
+    ] {
+        let fallback_chain = Vec::<common_enums::RoutableConnectors>::foreign_from((
+            primary_connector,
+            default_fallback.as_slice(),
+        ));
+        assert_eq!(fallback_chain, expected_chain);
+        assert_eq!(fallback_chain.first(), Some(&primary_connector));
+        assert_eq!(
+            fallback_chain
+                .iter()
+                .filter(|connector| **connector == primary_connector)
+                .count(),
+            1
+        );
+    }
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_validated_refund_for_partial_refund() {
+    let request = api_models::refunds::RefundRequest {
+        amount: Some(common_utils::types::MinorUnit::new(1000)),
+        ..Default::default()
+    };
+
+    let validated_refund = router_types::ValidatedRefund::foreign_try_from((
+        request,
+        common_utils::types::MinorUnit::new(6540),
+        common_utils::types::MinorUnit::new(2000),
+    ))
+    .unwrap();
+    assert_eq!(
+        validated_refund.amount,
+        common_utils::types::MinorUnit::new(1000)
+    );
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_validated_refund_for_over_refund() {
+    let request = api_models::refunds::RefundRequest {
+        amount: Some(common_utils::types::MinorUnit::new(5000)),
+        ..Default::default()
+    };
+
+    let result = router_types::ValidatedRefund::foreign_try_from((
+        request,
+        common_utils::types::MinorUnit::new(6540),
+        common_utils::types::MinorUnit::new(2000),
+    ));
+    assert!(matches!(
+        result.unwrap_err().current_context(),
+        errors::ApiErrorResponse::InvalidRequestData { .. }
+    ));
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_validated_refund_for_exact_full_refund() {
+    let request = api_models::refunds::RefundRequest {
+        amount: Some(common_utils::types::MinorUnit::new(4540)),
+        ..Default::default()
+    };
+
+    let validated_refund = router_types::ValidatedRefund::foreign_try_from((
+        request,
+        common_utils::types::MinorUnit::new(6540),
+        common_utils::types::MinorUnit::new(2000),
+    ))
+    .unwrap();
+    assert_eq!(
+        validated_refund.amount,
+        common_utils::types::MinorUnit::new(4540)
+    );
+}
+
+#[test]
+fn test_refund_timing_hint_for_instant_refund() {
+    let timing_hint =
+        router_types::RefundTimingHint::foreign_from(api_models::refunds::RefundType::Instant);
+    assert_eq!(timing_hint, router_types::RefundTimingHint::SameDay);
+    assert_eq!(timing_hint.to_string(), "same day");
+}
+
+#[test]
+fn test_refund_timing_hint_for_scheduled_refund() {
+    let timing_hint =
+        router_types::RefundTimingHint::foreign_from(api_models::refunds::RefundType::Scheduled);
+    assert_eq!(
+        timing_hint,
+        router_types::RefundTimingHint::BusinessDays { min: 5, max: 10 }
+    );
+    assert_eq!(timing_hint.to_string(), "T+5 to T+10 business days");
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_payment_attempt_response_card_issuer_for_card_attempt() {
+    let payment_attempt = storage::PaymentAttempt {
+        payment_method_data: Some(
+            serde_json::to_value(payments::AdditionalPaymentData::Card(Box::new(
+                payments::AdditionalCardInfo {
+                    card_issuer: Some("JP Morgan Chase".to_string()),
+                    card_isin: Some("424242".to_string()),
+                    last4: Some("4242".to_string()),
+                    ..Default::default()
+                },
+            )))
+            .unwrap(),
+        ),
+        ..get_payment_attempt(
+            storage_enums::AttemptStatus::Charged,
+            Some(storage_enums::PaymentMethod::Card),
+        )
+    };
+
+    let response = payments::PaymentAttemptResponse::foreign_from(payment_attempt);
+    assert_eq!(response.card_issuer.as_deref(), Some("JP Morgan Chase"));
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_payment_attempt_response_card_issuer_for_wallet_attempt() {
+    let payment_attempt = storage::PaymentAttempt {
+        payment_method_data: Some(serde_json::json!({ "wallet": {} })),
+        ..get_payment_attempt(
+            storage_enums::AttemptStatus::Charged,
+            Some(storage_enums::PaymentMethod::Wallet),
+        )
+    };
+
+    let response = payments::PaymentAttemptResponse::foreign_from(payment_attempt);
+    assert_eq!(response.card_issuer, None);
+}
+
+fn get_mandate(mandate_status: storage_enums::MandateStatus) -> storage::Mandate {
+    storage::Mandate {
+        mandate_id: "man_test".to_string(),
+        customer_id: common_utils::id_type::CustomerId::default(),
+        merchant_id: common_utils::id_type::MerchantId::default(),
+        payment_method_id: "pm_1".to_string(),
+        mandate_status,
+        mandate_type: storage_enums::MandateType::MultiUse,
+        customer_accepted_at: Some(common_utils::date_time::now()),
+        customer_ip_address: Some(Secret::new("127.0.0.1".to_string())),
+        customer_user_agent: Some("Mozilla/5.0".to_string()),
+        network_transaction_id: None,
+        previous_attempt_id: None,
+        created_at: common_utils::date_time::now(),
+        mandate_amount: None,
+        mandate_currency: None,
+        amount_captured: None,
+        connector: "stripe".to_string(),
+        connector_mandate_id: None,
+        start_date: None,
+        end_date: None,
+        metadata: None,
+        connector_mandate_ids: None,
+        original_payment_id: None,
+        merchant_connector_id: None,
+        updated_by: None,
+    }
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[test]
+fn test_mandate_webhook_event_for_active_mandate() {
+    let mandate = get_mandate(storage_enums::MandateStatus::Active);
+    let payment_method = get_payment_method(
+        storage_enums::PaymentMethod::Card,
+        storage_enums::PaymentMethodType::Credit,
+    );
+
+    let (event_type, mandate_response) = Option::<(
+        storage_enums::EventType,
+        api_models::mandates::MandateResponse,
+    )>::foreign_from((&mandate, &payment_method))
+    .unwrap();
+    assert_eq!(event_type, storage_enums::EventType::MandateActive);
+    assert_eq!(mandate_response.mandate_id, "man_test");
+    assert_eq!(
+        mandate_response.status,
+        storage_enums::MandateStatus::Active
+    );
+    assert_eq!(mandate_response.payment_method, "card");
+    assert_eq!(
+        mandate_response.payment_method_type.as_deref(),
+        Some("credit")
+    );
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[test]
+fn test_mandate_webhook_event_for_revoked_mandate() {
+    let mandate = get_mandate(storage_enums::MandateStatus::Revoked);
+    let payment_method = get_payment_method(
+        storage_enums::PaymentMethod::Card,
+        storage_enums::PaymentMethodType::Credit,
+    );
+
+    let (event_type, mandate_response) = Option::<(
+        storage_enums::EventType,
+        api_models::mandates::MandateResponse,
+    )>::foreign_from((&mandate, &payment_method))
+    .unwrap();
+    assert_eq!(event_type, storage_enums::EventType::MandateRevoked);
+    assert_eq!(
+        mandate_response.status,
+        storage_enums::MandateStatus::Revoked
+    );
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[test]
+fn test_mandate_webhook_event_for_pending_mandate() {
+    let mandate = get_mandate(storage_enums::MandateStatus::Pending);
+    let payment_method = get_payment_method(
+        storage_enums::PaymentMethod::Card,
+        storage_enums::PaymentMethodType::Credit,
+    );
+
+    assert!(Option::<(
+        storage_enums::EventType,
+        api_models::mandates::MandateResponse,
+    )>::foreign_from((&mandate, &payment_method))
+    .is_none());
+}
+
+#[test]
+fn test_external_authentication_details_challenge_mandated_for_challenge_flow() {
+    let authentication = storage::Authentication {
+        authentication_status: storage_enums::AuthenticationStatus::Pending,
+        acs_url: Some("https://acs.example.com/challenge".to_string()),
+        ..get_authentication()
+    };
+
+    let authentication_details =
+        payments::ExternalAuthenticationDetailsResponse::foreign_from(&authentication);
+    assert!(authentication_details.challenge_mandated);
+}
+
+#[test]
+fn test_external_authentication_details_challenge_mandated_for_frictionless_flow() {
+    let authentication = storage::Authentication {
+        authentication_status: storage_enums::AuthenticationStatus::Success,
+        acs_url: None,
+        ..get_authentication()
+    };
+
+    let authentication_details =
+        payments::ExternalAuthenticationDetailsResponse::foreign_from(&authentication);
+    assert!(!authentication_details.challenge_mandated);
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_attempt_latency_metrics_with_timestamps() {
+    let payment_attempt = storage::PaymentAttempt {
+        modified_at: payment_attempt
+            .created_at
+            .saturating_add(time::Duration::milliseconds(450)),
+        ..get_payment_attempt(
+            storage_enums::AttemptStatus::Charged,
+            Some(storage_enums::PaymentMethod::Card),
+        )
+    };
+
+    let latency_metrics =
+        Option::<payments::AttemptLatencyMetrics>::foreign_from(&payment_attempt).unwrap();
+    assert_eq!(latency_metrics.attempt_id, "pay_attempt_1");
+    assert_eq!(latency_metrics.connector, "stripe");
+    assert_eq!(latency_metrics.connector_call_duration_ms, 450);
+}
+
+#[cfg(feature = "v1")]
+#[test]
+fn test_attempt_latency_metrics_without_timestamps() {
+    let payment_attempt = get_payment_attempt(
+        storage_enums::AttemptStatus::Started,
+        Some(storage_enums::PaymentMethod::Card),
+    );
+
+    assert_eq!(
+        Option::<payments::AttemptLatencyMetrics>::foreign_from(&payment_attempt),
+        None
+    );
+}
+
+#[test]
+fn test_routing_preview_for_priority() {
+    let connector_selection = ConnectorSelection::Priority(vec![
+        routing_types::RoutableConnectorChoice {
+            choice_kind: api_models::routing::RoutableChoiceKind::FullStruct,
+            connector: api_enums::RoutableConnectors::Adyen,
+            merchant_connector_id: None,
+        },
+        routing_types::RoutableConnectorChoice {
+            choice_kind: api_models::routing::RoutableChoiceKind::FullStruct,
+            connector: api_enums::RoutableConnectors::Stripe,
+            merchant_connector_id: None,
+        },
+    ]);
+
+    let routing_preview = api_models::routing::RoutingPreview::foreign_from(&connector_selection);
+    assert_eq!(
+        routing_preview
+            .connectors
+            .iter()
+            .map(|preview| (preview.connector.connector, preview.split))
+            .collect::<Vec<_>>(),
+        vec![
+            (api_enums::RoutableConnectors::Adyen, None),
+            (api_enums::RoutableConnectors::Stripe, None),
+        ]
+    );
+    assert_eq!(routing_preview.warning, None);
+}
+
+#[test]
+fn test_routing_preview_for_volume_split() {
+    let routing_preview = api_models::routing::RoutingPreview::foreign_from(
+        &ConnectorSelection::VolumeSplit(get_volume_splits(&[60, 40])),
+    );
+    assert_eq!(
+        routing_preview
+            .connectors
+            .iter()
+            .map(|preview| preview.split)
+            .collect::<Vec<_>>(),
+        vec![Some(60), Some(40)]
+    );
+    assert_eq!(routing_preview.warning, None);
+}
+
+#[test]
+fn test_routing_preview_for_incomplete_volume_split() {
+    let routing_preview = api_models::routing::RoutingPreview::foreign_from(
+        &ConnectorSelection::VolumeSplit(get_volume_splits(&[60, 30])),
+    );
+    assert_eq!(routing_preview.connectors.len(), 2);
+    assert!(routing_preview
+        .warning
+        .is_some_and(|warning| warning.contains("found 90")));
+}
+
+#[test]
+fn test_dispute_category_for_fraud_reason_code() {
+    let dispute = storage::Dispute {
+        connector_reason_code: Some("10.4".to_string()),
+        ..get_dispute(serde_json::json!({}))
+    };
+
+    let dispute_response = api_models::disputes::DisputeResponse::foreign_from(dispute);
+    assert_eq!(
+        dispute_response.dispute_category,
+        Some(api_enums::DisputeCategory::Fraud)
+    );
+}
+
+#[test]
+fn test_dispute_category_for_duplicate_reason_code() {
+    let dispute = storage::Dispute {
+        connector_reason_code: Some("4834".to_string()),
+        ..get_dispute(serde_json::json!({}))
+    };
+
+    let dispute_response = api_models::disputes::DisputeResponse::foreign_from(dispute);
+    assert_eq!(
+        dispute_response.dispute_category,
+        Some(api_enums::DisputeCategory::Duplicate)
+    );
+}
+
+#[test]
+fn test_dispute_category_for_unknown_reason_code() {
+    let dispute = storage::Dispute {
+        connector_reason_code: Some("9999".to_string()),
+        ..get_dispute(serde_json::json!({}))
+    };
+
+    let dispute_response = api_models::disputes::DisputeResponse::foreign_from(dispute);
+    assert_eq!(dispute_response.dispute_category, None);
+}
@@ -1122,13 +1122,7 @@ where
                 .collect()
         });
 
-    if matches!(
-        status,
-        enums::IntentStatus::Succeeded
-            | enums::IntentStatus::Failed
-            | enums::IntentStatus::PartiallyCaptured
-            | enums::IntentStatus::RequiresMerchantAction
-    ) {
+    if let Some(event_type) = get_payments_webhook_event_type(&format!("{operation:?}"), status) {
         let payments_response = crate::core::payments::transformers::payments_to_payments_response(
             payment_data,
             captures,
@@ -1142,8 +1136,6 @@ where
             None,
         )?;
 
-        let event_type = ForeignFrom::foreign_from(status);
-
         if let services::ApplicationResponse::JsonWithHeaders((payments_response_json, _)) =
             payments_response
         {
@@ -1153,39 +1145,57 @@ where
             // the current thread and the lifecycle of spawn thread is not handled by runtime.
             // So when server shutdown won't wait for this thread's completion.
 
-            if let Some(event_type) = event_type {
-                tokio::spawn(
-                    async move {
-                        let primary_object_created_at = payments_response_json.created;
-                        Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
-                            cloned_state,
-                            merchant_account,
-                            business_profile,
-                            &cloned_key_store,
-                            event_type,
-                            diesel_models::enums::EventClass::Payments,
-                            payment_id.get_string_repr().to_owned(),
-                            diesel_models::enums::EventObjectType::PaymentDetails,
-                            webhooks::OutgoingWebhookContent::PaymentDetails(Box::new(
-                                payments_response_json,
-                            )),
-                            primary_object_created_at,
-                        ))
-                        .await
-                    }
-                    .in_current_span(),
-                );
-            } else {
-                logger::warn!(
-                    "Outgoing webhook not sent because of missing event type status mapping"
-                );
-            }
+            tokio::spawn(
+                async move {
+                    let primary_object_created_at = payments_response_json.created;
+                    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+                        cloned_state,
+                        merchant_account,
+                        business_profile,
+                        &cloned_key_store,
+                        event_type,
+                        diesel_models::enums::EventClass::Payments,
+                        payment_id.get_string_repr().to_owned(),
+                        diesel_models::enums::EventObjectType::PaymentDetails,
+                        webhooks::OutgoingWebhookContent::PaymentDetails(Box::new(
+                            payments_response_json,
+                        )),
+                        primary_object_created_at,
+                    ))
+                    .await
+                }
+                .in_current_span(),
+            );
         }
     }
 
     Ok(())
 }
 
+/// Returns the event type of the outgoing webhook to be sent after a payment operation, if any.
+/// Payments awaiting input notify the merchant only once, when they are created, as the intent
+/// stays in those statuses across updates.
+#[cfg(feature = "v1")]
+fn get_payments_webhook_event_type(
+    operation_name: &str,
+    status: enums::IntentStatus,
+) -> Option<enums::EventType> {
+    match status {
+        enums::IntentStatus::RequiresPaymentMethod | enums::IntentStatus::RequiresConfirmation => {
+            (operation_name == "PaymentCreate").then_some(enums::EventType::PaymentCreated)
+        }
+        enums::IntentStatus::Succeeded
+        | enums::IntentStatus::Failed
+        | enums::IntentStatus::PartiallyCaptured
+        | enums::IntentStatus::RequiresMerchantAction => ForeignFrom::foreign_from(status),
+        enums::IntentStatus::Cancelled
+        | enums::IntentStatus::Processing
+        | enums::IntentStatus::RequiresCustomerAction
+        | enums::IntentStatus::RequiresCapture
+        | enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+    }
+}
+
 type Handle<T> = tokio::task::JoinHandle<RouterResult<T>>;
 
 pub async fn flatten_join_error<T>(handle: Handle<T>) -> RouterResult<T> {
@@ -1197,3 +1207,32 @@ pub async fn flatten_join_error<T>(handle: Handle<T>) -> RouterResult<T> {
             .attach_printable("Join Error"),
     }
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payment_created_event_only_on_payment_create() {
+        for status in [
+            enums::IntentStatus::RequiresPaymentMethod,
+            enums::IntentStatus::RequiresConfirmation,
+        ] {
+            assert_eq!(
+                get_payments_webhook_event_type("PaymentCreate", status),
+                Some(enums::EventType::PaymentCreated)
+            );
+            for operation_name in ["PaymentUpdate", "PaymentConfirm", "PaymentStatus"] {
+                assert_eq!(
+                    get_payments_webhook_event_type(operation_name, status),
+                    None
+                );
+            }
+        }
+
+        assert_eq!(
+            get_payments_webhook_event_type("PaymentConfirm", enums::IntentStatus::Succeeded),
+            Some(enums::EventType::PaymentSucceeded)
+        );
+    }
+}
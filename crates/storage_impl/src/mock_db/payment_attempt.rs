@@ -114,6 +114,17 @@ impl PaymentAttemptInterface for MockDb {
         Err(StorageError::MockDbError)?
     }
 
+    #[cfg(feature = "v1")]
+    async fn find_attempts_by_merchant_id_attempt_ids(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _attempt_ids: &[String],
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<PaymentAttempt>, StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(StorageError::MockDbError)?
+    }
+
     #[cfg(feature = "v1")]
     #[allow(clippy::panic)]
     async fn insert_payment_attempt(
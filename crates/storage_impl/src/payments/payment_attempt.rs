@@ -350,6 +350,28 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
             })
     }
 
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_attempts_by_merchant_id_attempt_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        attempt_ids: &[String],
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPaymentAttempt::find_by_merchant_id_attempt_ids(&conn, merchant_id, attempt_ids)
+            .await
+            .map_err(|er| {
+                let new_err = diesel_error_to_data_error(er.current_context());
+                er.change_context(new_err)
+            })
+            .map(|a| {
+                a.into_iter()
+                    .map(PaymentAttempt::from_storage_model)
+                    .collect()
+            })
+    }
+
     #[cfg(feature = "v1")]
     #[instrument(skip_all)]
     async fn find_payment_attempt_by_attempt_id_merchant_id(
@@ -1269,6 +1291,19 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
         }
     }
 
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_attempts_by_merchant_id_attempt_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        attempt_ids: &[String],
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PaymentAttempt>, errors::StorageError> {
+        self.router_store
+            .find_attempts_by_merchant_id_attempt_ids(merchant_id, attempt_ids, storage_scheme)
+            .await
+    }
+
     #[cfg(all(feature = "v1", feature = "olap"))]
     #[instrument(skip_all)]
     async fn get_filters_for_payments(
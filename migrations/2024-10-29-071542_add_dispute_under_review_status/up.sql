@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TYPE "DisputeStatus" ADD VALUE IF NOT EXISTS 'dispute_under_review';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'dispute_under_review';
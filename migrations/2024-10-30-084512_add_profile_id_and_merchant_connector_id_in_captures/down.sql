@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE captures
+DROP COLUMN IF EXISTS profile_id,
+DROP COLUMN IF EXISTS merchant_connector_id;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE captures
+ADD COLUMN IF NOT EXISTS profile_id VARCHAR(64),
+ADD COLUMN IF NOT EXISTS merchant_connector_id VARCHAR(32);